@@ -11,6 +11,10 @@ fn main() {
         .probe("notcurses")
         .unwrap();
 
+    // Expose the version of the headers used to generate the bindings,
+    // to compare it at runtime with the version of the linked library.
+    println!("cargo:rustc-env=NOTCURSES_BUILD_VERSION={}", plib.version);
+
     // Tell cargo to invalidate the built crate whenever the wrapper changes
     println!("cargo:rerun-if-changed=build/wrapper.h");
 
@@ -29,19 +33,21 @@ fn main() {
         // Remove warnings about improper_ctypes
         .blacklist_function("strtold")
         .blacklist_function("wcstold")
+        // Defined by hand in `bindings.rs`, since C mutates them behind
+        // shared references.
+        .blacklist_type("ncplane")
+        .blacklist_type("notcurses")
         // Don't derive the Copy trait on types with destructors.
         .no_copy("ncdirect")
         .no_copy("ncdplot")
         .no_copy("ncfdplane")
         .no_copy("ncmenu")
         .no_copy("ncmultiselector")
-        .no_copy("ncplane")
         .no_copy("ncreader")
         .no_copy("ncreel")
         .no_copy("ncselector")
         .no_copy("ncuplot")
         .no_copy("ncvisual")
-        .no_copy("notcurses")
         // Tell cargo to invalidate the built crate whenever any of the
         // included header files changed.
         .parse_callbacks(Box::new(bindgen::CargoCallbacks));
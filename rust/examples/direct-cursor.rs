@@ -5,9 +5,9 @@
 
 use libnotcurses_sys::*;
 
-fn main() {
+fn main() -> NcResult<()> {
     unsafe {
-        let ncd = NcDirect::new();
+        let ncd = NcDirect::new()?;
 
         let cols = ncdirect_dim_x(ncd);
         let rows = ncdirect_dim_y(ncd);
@@ -43,4 +43,5 @@ fn main() {
 
         ncdirect_stop(ncd);
     }
+    Ok(())
 }
@@ -7,9 +7,9 @@ use std::ffi::CString;
 // This time we are gonna use the notcurses library through the `sys` namespace
 use libnotcurses_sys as sys;
 
-fn main() {
+fn main() -> sys::NcResult<()> {
     unsafe {
-        let ncd = sys::NcDirect::new();
+        let ncd = sys::NcDirect::new()?;
 
         render_image(&mut *ncd, sys::NCBLIT_1x1);
         render_image(&mut *ncd, sys::NCBLIT_2x1);
@@ -17,6 +17,7 @@ fn main() {
 
         sys::ncdirect_stop(ncd);
     }
+    Ok(())
 }
 
 fn render_image(ncd: &mut sys::NcDirect, blit: sys::NcBlitter) {
@@ -1,8 +1,8 @@
 use libnotcurses_sys::*;
 
-fn main() {
+fn main() -> NcResult<()> {
     unsafe {
-        let nc = Notcurses::new();
+        let nc = Notcurses::new()?;
 
         // use standard plane
         let stdplane = notcurses_stdplane(nc);
@@ -17,4 +17,5 @@ fn main() {
 
         notcurses_stop(nc);
     }
+    Ok(())
 }
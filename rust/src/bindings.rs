@@ -14,6 +14,26 @@ pub mod ffi {
     //! API, while structs, enums and constants are type aliased or wrapped up.
     //!
     include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+    /// Opaque notcurses context.
+    ///
+    /// The C library mutates it through pointers derived from shared
+    /// references, so its contents live in an `UnsafeCell`.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct notcurses {
+        _unused: core::cell::UnsafeCell<[u8; 0]>,
+    }
+
+    /// Opaque notcurses plane.
+    ///
+    /// The C library mutates it through pointers derived from shared
+    /// references, so its contents live in an `UnsafeCell`.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct ncplane {
+        _unused: core::cell::UnsafeCell<[u8; 0]>,
+    }
 }
 
 // Miscellaneous ---------------------------------------------------------------
@@ -383,6 +403,7 @@ pub use ffi::{
     ncplane_reparent,
     ncplane_reparent_family,
     ncplane_resize,
+    ncplane_resize_maximize,
     ncplane_resize_realign,
     ncplane_resizecb,
     ncplane_rgba,
@@ -405,6 +426,7 @@ pub use ffi::{
     ncplane_set_fg_rgb,
     ncplane_set_fg_rgb8,
     ncplane_set_fg_rgb8_clipped,
+    ncplane_set_resizecb,
     ncplane_set_scrolling,
     ncplane_set_styles,
     ncplane_set_userptr,
@@ -0,0 +1,75 @@
+//! `NcCapabilities` methods and associated functions.
+
+use crate::{NcCapabilities, NcDirect, NcStyle, NcStyleSet, Notcurses};
+
+/// # `NcCapabilities` Constructors
+impl NcCapabilities {
+    /// Gathers the capabilities detected by a full mode context.
+    pub fn from_notcurses(nc: &Notcurses) -> Self {
+        unsafe {
+            Self {
+                utf8: crate::notcurses_canutf8(nc),
+                images: crate::notcurses_canopen_images(nc),
+                palette_size: crate::notcurses_palette_size(nc),
+                truecolor: Some(crate::notcurses_cantruecolor(nc)),
+                fade: Some(crate::notcurses_canfade(nc)),
+                change_color: Some(crate::notcurses_canchangecolor(nc)),
+                videos: Some(crate::notcurses_canopen_videos(nc)),
+                sixel: Some(crate::notcurses_cansixel(nc)),
+                styles: Some(NcStyleSet::from_mask(
+                    crate::notcurses_supported_styles(nc) as u16
+                )),
+            }
+        }
+    }
+
+    /// Gathers the capabilities detected by a direct mode context.
+    ///
+    /// Only `utf8`, `images` and `palette_size` are detected in direct mode.
+    pub fn from_direct(ncd: &NcDirect) -> Self {
+        unsafe {
+            Self {
+                utf8: crate::ncdirect_canutf8(ncd),
+                images: crate::ncdirect_canopen_images(ncd),
+                palette_size: crate::ncdirect_palette_size(ncd),
+                truecolor: None,
+                fade: None,
+                change_color: None,
+                videos: None,
+                sixel: None,
+                styles: None,
+            }
+        }
+    }
+}
+
+/// # `NcCapabilities` Methods
+impl NcCapabilities {
+    /// Returns whether `style` is supported, or `None` if unknown.
+    pub fn supports(&self, style: NcStyle) -> Option<bool> {
+        self.styles.map(|s| s.contains(style))
+    }
+
+    /// Returns true if there's any color support at all.
+    pub fn has_color(&self) -> bool {
+        self.palette_size > 1 || self.truecolor == Some(true)
+    }
+}
+
+/// # `Notcurses` capabilities
+impl Notcurses {
+    /// Returns a snapshot of the terminal capabilities.
+    pub fn capabilities(&self) -> NcCapabilities {
+        NcCapabilities::from_notcurses(self)
+    }
+}
+
+/// # `NcDirect` capabilities
+impl NcDirect {
+    /// Returns a snapshot of the terminal capabilities.
+    ///
+    /// See [`NcCapabilities::from_direct`].
+    pub fn capabilities(&self) -> NcCapabilities {
+        NcCapabilities::from_direct(self)
+    }
+}
@@ -0,0 +1,94 @@
+//! `NcCapabilities`
+
+// functions already exported by bindgen : 12
+// ------------------------------------------
+// (W) wrap: 12 / 0
+// (#) test:  0 / 12
+// ------------------------------------------
+//W ncdirect_canopen_images
+//W ncdirect_canutf8
+//W ncdirect_palette_size
+//W notcurses_canchangecolor
+//W notcurses_canfade
+//W notcurses_canopen_images
+//W notcurses_canopen_videos
+//W notcurses_cansixel
+//W notcurses_cantruecolor
+//W notcurses_canutf8
+//W notcurses_palette_size
+//W notcurses_supported_styles
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+use core::fmt;
+
+use crate::NcStyleSet;
+
+/// A snapshot of the capabilities of the terminal,
+/// as detected by a [`Notcurses`][crate::Notcurses] or an
+/// [`NcDirect`][crate::NcDirect] context.
+///
+/// Direct mode can't detect all of them, so those are `None`
+/// when gathered from an `NcDirect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NcCapabilities {
+    /// Is the encoding UTF-8?
+    pub utf8: bool,
+
+    /// Can we open images? (Notcurses was built with multimedia support)
+    pub images: bool,
+
+    /// The number of simultaneous colors supported, from the palette.
+    ///
+    /// It is 1 if there is no color support.
+    pub palette_size: u32,
+
+    /// Can we directly specify RGB values per cell, or only use palettes?
+    pub truecolor: Option<bool>,
+
+    /// Can we fade? Fading requires either the "rgb" or "ccc" terminfo capability.
+    pub fade: Option<bool>,
+
+    /// Can we set the "hardware" palette? Requires the "ccc" terminfo capability.
+    pub change_color: Option<bool>,
+
+    /// Can we load videos? (Notcurses was built with multimedia support)
+    pub videos: Option<bool>,
+
+    /// Can we blit to Sixel?
+    pub sixel: Option<bool>,
+
+    /// The styles supported by the terminal.
+    pub styles: Option<NcStyleSet>,
+}
+
+/// A human readable report, one capability per line.
+///
+/// Capabilities that couldn't be detected are shown as `unknown`.
+impl fmt::Display for NcCapabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn yes_no(f: &mut fmt::Formatter<'_>, name: &str, value: Option<bool>) -> fmt::Result {
+            let value = match value {
+                Some(true) => "yes",
+                Some(false) => "no",
+                None => "unknown",
+            };
+            writeln!(f, "{:<13}{}", name, value)
+        }
+        yes_no(f, "utf8:", Some(self.utf8))?;
+        yes_no(f, "images:", Some(self.images))?;
+        yes_no(f, "videos:", self.videos)?;
+        yes_no(f, "sixel:", self.sixel)?;
+        yes_no(f, "truecolor:", self.truecolor)?;
+        yes_no(f, "fade:", self.fade)?;
+        yes_no(f, "changecolor:", self.change_color)?;
+        writeln!(f, "{:<13}{}", "palette:", self.palette_size)?;
+        match self.styles {
+            Some(styles) => write!(f, "{:<13}{}", "styles:", styles),
+            None => write!(f, "{:<13}unknown", "styles:"),
+        }
+    }
+}
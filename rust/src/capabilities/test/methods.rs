@@ -0,0 +1,38 @@
+//! Test `NcCapabilities` methods and associated functions.
+
+use serial_test::serial;
+
+use crate::{NcCapabilities, NcStyle};
+
+#[test]
+fn nccapabilities_display() {
+    let caps = NcCapabilities {
+        utf8: true,
+        images: false,
+        palette_size: 256,
+        truecolor: Some(true),
+        fade: None,
+        change_color: Some(false),
+        videos: None,
+        sixel: None,
+        styles: Some(NcStyle::Bold.into()),
+    };
+    assert_eq![Some(true), caps.supports(NcStyle::Bold)];
+    assert_eq![Some(false), caps.supports(NcStyle::Italic)];
+    assert![caps.has_color()];
+
+    let report = caps.to_string();
+    assert![report.starts_with("utf8:        yes\nimages:      no\n")];
+    assert![report.contains("fade:        unknown\n")];
+    assert![report.contains("palette:     256\n")];
+    assert![report.ends_with("styles:      bold")];
+}
+
+#[test]
+#[serial]
+fn notcurses_capabilities() {
+    let nc = crate::FullMode::new().unwrap();
+    let caps = nc.capabilities();
+    assert![caps.truecolor.is_some() && caps.styles.is_some()];
+    print!("[{:?}] ", caps);
+}
@@ -0,0 +1,4 @@
+//! `NcCapabilities` tests
+
+#[cfg(test)]
+mod methods;
@@ -1,8 +1,9 @@
 //! `NcCell` methods and associated functions.
 
 use crate::{
-    cell_extract, cell_load, cstring, NcCell, NcChannelPair, NcEgc, NcEgcBackstop, NcPlane,
-    NcStyleMask, NCRESULT_ERR,
+    cell_extract, cell_load,
+    error::{nc_cstring, nc_result},
+    NcCell, NcChannelPair, NcEgc, NcEgcBackstop, NcPlane, NcResult, NcStyleMask,
 };
 
 /// # `NcCell` Constructors
@@ -27,20 +28,19 @@ impl NcCell {
 
     /// New NcCell, expects an [NcPlane] and a [char].
     #[inline]
-    pub fn with_char(plane: &mut NcPlane, ch: char) -> Self {
-        let mut cell = Self::new();
-        let result = unsafe { cell_load(plane, &mut cell, cstring![ch.to_string()]) };
-        debug_assert_ne![NCRESULT_ERR, result];
-        cell
+    pub fn with_char(plane: &mut NcPlane, ch: char) -> NcResult<Self> {
+        Self::with_str(plane, ch.encode_utf8(&mut [0; 4]))
     }
 
     /// New NcCell, expects an [NcPlane] and a &[str].
     #[inline]
-    pub fn with_str(plane: &mut NcPlane, string: &str) -> Self {
+    pub fn with_str(plane: &mut NcPlane, string: &str) -> NcResult<Self> {
         let mut cell = Self::new();
-        let result = unsafe { cell_load(plane, &mut cell, cstring![string]) };
-        debug_assert_ne![NCRESULT_ERR, result];
-        cell
+        let cstring = nc_cstring("cell_load", string)?;
+        nc_result("cell_load", unsafe {
+            cell_load(plane, &mut cell, cstring.as_ptr())
+        })?;
+        Ok(cell)
     }
 
     /// New NcCell, blank.
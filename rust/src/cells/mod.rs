@@ -64,7 +64,9 @@ mod test;
 
 mod methods;
 mod reimplemented;
+mod style;
 pub use reimplemented::*;
+pub use style::*;
 
 // NcCell
 /// A coordinate on an [`NcPlane`][crate::NcPlane] storing 128 bits of data
@@ -347,6 +349,7 @@ pub type NcEgcBackstop = u8;
 /// - [`NCSTYLE_STRUCK`]
 /// - [`NCSTYLE_UNDERLINE`]
 ///
+/// See also [`NcStyleSet`], its typed counterpart.
 ///
 /// ## Diagram
 ///
@@ -9,8 +9,8 @@ use crate::{
     channels_fg_rgb, channels_fg_rgb8, channels_set_bchannel, channels_set_bg_alpha,
     channels_set_bg_default, channels_set_bg_rgb, channels_set_bg_rgb8, channels_set_fchannel,
     channels_set_fg_alpha, channels_set_fg_default, channels_set_fg_rgb, channels_set_fg_rgb8,
-    NcAlphaBits, NcCell, NcChannel, NcChannelPair, NcColor, NcEgc, NcPaletteIndex, NcPlane,
-    NcResult, NcRgb, NcStyleMask, NCCELL_ALPHA_OPAQUE, NCCELL_BGDEFAULT_MASK, NCCELL_BG_PALETTE,
+    NcAlphaBits, NcCell, NcChannel, NcChannelPair, NcColor, NcEgc, NcIntResult, NcPaletteIndex,
+    NcPlane, NcRgb, NcStyleMask, NCCELL_ALPHA_OPAQUE, NCCELL_BGDEFAULT_MASK, NCCELL_BG_PALETTE,
     NCCELL_FGDEFAULT_MASK, NCCELL_FG_PALETTE, NCCELL_NOBACKGROUND_MASK, NCCELL_WIDEASIAN_MASK,
     NCRESULT_ERR, NCRESULT_OK, NCSTYLE_MASK,
 };
@@ -366,7 +366,7 @@ pub unsafe fn cell_prime(
     gcluster: NcEgc,
     style: NcStyleMask,
     channels: NcChannelPair,
-) -> NcResult {
+) -> NcIntResult {
     cell.stylemask = style;
     cell.channels = channels;
     unsafe { cell_load(plane, cell, gcluster as u32 as *const i8) }
@@ -391,10 +391,10 @@ pub unsafe fn cells_load_box(
     hl: &mut NcCell,
     vl: &mut NcCell,
     gcluster: NcEgc,
-) -> NcResult {
+) -> NcIntResult {
     // mutable copy for pointer arithmetics:
     let mut gclu = gcluster as u32 as *const i8;
-    let mut ulen: NcResult;
+    let mut ulen: NcIntResult;
 
     ulen = unsafe { cell_prime(plane, ul, gcluster, style, channels) };
 
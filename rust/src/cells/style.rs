@@ -0,0 +1,221 @@
+//! `NcStyle` & `NcStyleSet`
+
+use core::fmt;
+
+use crate::{
+    NcStyleMask, NCSTYLE_BLINK, NCSTYLE_BOLD, NCSTYLE_DIM, NCSTYLE_INVIS, NCSTYLE_ITALIC,
+    NCSTYLE_PROTECT, NCSTYLE_REVERSE, NCSTYLE_STANDOUT, NCSTYLE_STRUCK, NCSTYLE_UNDERLINE,
+};
+
+/// A single styling attribute, one of the `NCSTYLE_*` flags of an [`NcStyleMask`].
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NcStyle {
+    /// Protected from being overwritten ([`NCSTYLE_PROTECT`]).
+    Protect = NCSTYLE_PROTECT,
+    /// Invisible ([`NCSTYLE_INVIS`]).
+    Invis = NCSTYLE_INVIS,
+    /// Bold ([`NCSTYLE_BOLD`]).
+    Bold = NCSTYLE_BOLD,
+    /// Dim ([`NCSTYLE_DIM`]).
+    Dim = NCSTYLE_DIM,
+    /// Blinking ([`NCSTYLE_BLINK`]).
+    Blink = NCSTYLE_BLINK,
+    /// Reversed foreground and background ([`NCSTYLE_REVERSE`]).
+    Reverse = NCSTYLE_REVERSE,
+    /// Underlined ([`NCSTYLE_UNDERLINE`]).
+    Underline = NCSTYLE_UNDERLINE,
+    /// Standout ([`NCSTYLE_STANDOUT`]).
+    Standout = NCSTYLE_STANDOUT,
+    /// Italic ([`NCSTYLE_ITALIC`]).
+    Italic = NCSTYLE_ITALIC,
+    /// Struck through ([`NCSTYLE_STRUCK`]).
+    Struck = NCSTYLE_STRUCK,
+}
+
+/// # `NcStyle` Methods
+impl NcStyle {
+    /// All the styles, from the lowest to the highest bit.
+    pub const ALL: [NcStyle; 10] = [
+        NcStyle::Protect,
+        NcStyle::Invis,
+        NcStyle::Bold,
+        NcStyle::Dim,
+        NcStyle::Blink,
+        NcStyle::Reverse,
+        NcStyle::Underline,
+        NcStyle::Standout,
+        NcStyle::Italic,
+        NcStyle::Struck,
+    ];
+
+    /// Returns the `NCSTYLE_*` flag of this style.
+    pub const fn mask(self) -> NcStyleMask {
+        self as NcStyleMask
+    }
+
+    /// Returns the name of this style, in lowercase.
+    pub fn name(self) -> &'static str {
+        match self {
+            NcStyle::Protect => "protect",
+            NcStyle::Invis => "invis",
+            NcStyle::Bold => "bold",
+            NcStyle::Dim => "dim",
+            NcStyle::Blink => "blink",
+            NcStyle::Reverse => "reverse",
+            NcStyle::Underline => "underline",
+            NcStyle::Standout => "standout",
+            NcStyle::Italic => "italic",
+            NcStyle::Struck => "struck",
+        }
+    }
+}
+
+impl From<NcStyle> for NcStyleMask {
+    fn from(style: NcStyle) -> Self {
+        style.mask()
+    }
+}
+
+impl fmt::Display for NcStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A set of [`NcStyle`]s, backed by an [`NcStyleMask`].
+///
+/// Bits not corresponding to any known style are ignored.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NcStyleSet(NcStyleMask);
+
+/// # `NcStyleSet` Methods
+impl NcStyleSet {
+    /// New empty `NcStyleSet`.
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// New `NcStyleSet` from a raw [`NcStyleMask`].
+    pub fn from_mask(mask: NcStyleMask) -> Self {
+        Self(
+            NcStyle::ALL
+                .iter()
+                .fold(0, |acc, s| acc | (mask & s.mask())),
+        )
+    }
+
+    /// Returns the raw [`NcStyleMask`].
+    pub const fn mask(self) -> NcStyleMask {
+        self.0
+    }
+
+    /// Returns true if there are no styles in the set.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of styles in the set.
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns true if `style` is in the set.
+    pub fn contains(self, style: NcStyle) -> bool {
+        self.0 & style.mask() != 0
+    }
+
+    /// Returns true if every style in `other` is also in this set.
+    pub fn is_superset(self, other: NcStyleSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds `style` to the set.
+    pub fn insert(&mut self, style: NcStyle) {
+        self.0 |= style.mask();
+    }
+
+    /// Removes `style` from the set.
+    pub fn remove(&mut self, style: NcStyle) {
+        self.0 &= !style.mask();
+    }
+
+    /// Returns an iterator over the styles in the set, from the lowest bit.
+    pub fn iter(self) -> impl Iterator<Item = NcStyle> {
+        NcStyle::ALL
+            .iter()
+            .copied()
+            .filter(move |s| self.contains(*s))
+    }
+}
+
+impl From<NcStyle> for NcStyleSet {
+    fn from(style: NcStyle) -> Self {
+        Self(style.mask())
+    }
+}
+
+impl From<NcStyleSet> for NcStyleMask {
+    fn from(set: NcStyleSet) -> Self {
+        set.0
+    }
+}
+
+impl core::iter::FromIterator<NcStyle> for NcStyleSet {
+    fn from_iter<I: IntoIterator<Item = NcStyle>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<NcStyle> for NcStyleSet {
+    fn extend<I: IntoIterator<Item = NcStyle>>(&mut self, iter: I) {
+        for style in iter {
+            self.insert(style);
+        }
+    }
+}
+
+impl core::ops::BitOr for NcStyleSet {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOr<NcStyle> for NcStyleSet {
+    type Output = Self;
+    fn bitor(self, rhs: NcStyle) -> Self {
+        Self(self.0 | rhs.mask())
+    }
+}
+
+impl core::ops::BitAnd for NcStyleSet {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Debug for NcStyleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Lists the style names, separated by `|`, or `none` if the set is empty.
+impl fmt::Display for NcStyleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("none");
+        }
+        for (i, style) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("|")?;
+            }
+            f.write_str(style.name())?;
+        }
+        Ok(())
+    }
+}
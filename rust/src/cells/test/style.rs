@@ -0,0 +1,27 @@
+//! Test `NcStyle` & `NcStyleSet`.
+
+use crate::{NcStyle, NcStyleSet, NCSTYLE_BOLD, NCSTYLE_ITALIC, NCSTYLE_MASK};
+
+#[test]
+fn ncstyleset() {
+    let set = NcStyleSet::from_mask(NCSTYLE_BOLD | NCSTYLE_ITALIC);
+    assert![set.contains(NcStyle::Bold)];
+    assert![!set.contains(NcStyle::Dim)];
+    assert_eq![2, set.len()];
+    assert_eq![
+        vec![NcStyle::Bold, NcStyle::Italic],
+        set.iter().collect::<Vec<_>>()
+    ];
+    assert_eq![
+        set,
+        [NcStyle::Italic, NcStyle::Bold].iter().copied().collect()
+    ];
+    assert_eq!["bold|italic", set.to_string()];
+    assert_eq!["none", NcStyleSet::new().to_string()];
+    assert_eq![
+        NcStyle::ALL.len(),
+        NcStyleSet::from_mask(NCSTYLE_MASK).len()
+    ];
+    // from the lowest to the highest bit
+    assert![NcStyle::ALL.windows(2).all(|w| w[0].mask() < w[1].mask())];
+}
@@ -1,7 +1,13 @@
 //! `NcDirect` methods and associated functions.
 
-use crate::{ncdirect_init, NcDirect, NcDirectFlags};
-use core::ptr::{null, null_mut};
+use crate::{
+    error::nc_ptr, ncdirect_getc, ncdirect_init, NcDirect, NcDirectFlags, NcEvent, NcInput,
+    NcResult, NcSignalSet, NcTime,
+};
+use core::{
+    ptr::{null, null_mut},
+    time::Duration,
+};
 
 impl NcDirect {
     /// A simple ncdirect_init() wrapper using the default options.
@@ -11,9 +17,9 @@ impl NcDirect {
     /// Direct mode supports a limited subset of notcurses routines,
     /// and neither supports nor requires notcurses_render(). This can be
     /// used to add color and styling to text in the standard output paradigm.
-    //
-    // Returns NULL on error, including any failure initializing terminfo.
-    pub unsafe fn new<'a>() -> &'a mut NcDirect {
+    ///
+    /// Returns an error on failure, including any failure initializing terminfo.
+    pub unsafe fn new<'a>() -> NcResult<&'a mut NcDirect> {
         Self::with_flags(0)
     }
 
@@ -23,7 +29,50 @@ impl NcDirect {
     /// - NCDIRECT_OPTION_INHIBIT_CBREAK
     /// - NCDIRECT_OPTION_INHIBIT_SETLOCALE
     ///
-    pub unsafe fn with_flags<'a>(flags: NcDirectFlags) -> &'a mut NcDirect {
-        &mut *ncdirect_init(null(), null_mut(), flags)
+    pub unsafe fn with_flags<'a>(flags: NcDirectFlags) -> NcResult<&'a mut NcDirect> {
+        let ncd = nc_ptr("ncdirect_init", ncdirect_init(null(), null_mut(), flags))?;
+        Ok(&mut *ncd.as_ptr())
+    }
+}
+
+/// # `NcDirect` methods
+impl NcDirect {
+    /// Waits for an input event, with `sigmask` as the signal mask during the
+    /// wait, and returns its raw `char32_t` code, filling in the `input`.
+    ///
+    /// See [`Notcurses.getc`][crate::Notcurses#method.getc].
+    pub fn getc(
+        &mut self,
+        timeout: Option<Duration>,
+        sigmask: &NcSignalSet,
+        input: &mut NcInput,
+    ) -> u32 {
+        let ts = timeout.map(NcTime::from);
+        let mut sigmask = *sigmask;
+        unsafe {
+            ncdirect_getc(
+                self,
+                ts.as_ref().map_or(null(), |ts| ts),
+                sigmask.as_mut_ptr(),
+                input,
+            )
+        }
+    }
+
+    /// Waits for an input event, blocking no longer than `timeout`, if any,
+    /// and letting any signal not in `sigmask` interrupt the wait.
+    ///
+    /// See [`Notcurses.get_event_masked`][crate::Notcurses#method.get_event_masked].
+    pub fn get_event_masked(
+        &mut self,
+        timeout: Option<Duration>,
+        sigmask: &NcSignalSet,
+    ) -> NcResult<Option<NcEvent>> {
+        let mut input = NcInput::new();
+        let id = self.getc(timeout, sigmask, &mut input);
+        if id == u32::MAX && timeout.is_some() {
+            return Ok(None);
+        }
+        NcEvent::from_input(id, &input).map(Some)
     }
 }
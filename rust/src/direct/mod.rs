@@ -43,11 +43,21 @@
 //  ncdirect_styles_on
 //  ncdirect_styles_set
 //  ncdirect_vline_interp
+//
+// functions manually reimplemented: 2
+// ------------------------------------------
+// (+) done: 2 / 0
+// (#) test: 0 / 2
+// ------------------------------------------
+// + ncdirect_getc_blocking
+// + ncdirect_getc_nblock
 
 #[cfg(test)]
 mod test;
 
 mod methods;
+mod reimplemented;
+pub use reimplemented::*;
 
 /// Minimal notcurses instances for styling text
 pub type NcDirect = crate::bindings::ffi::ncdirect;
@@ -0,0 +1,37 @@
+//! `ncdirect_*` reimplemented functions.
+
+use core::ptr::null;
+
+use crate::{ncdirect_getc, NcDirect, NcInput, NcSignalSet, NcTime};
+
+/// 'input' may be NULL if the caller is uninterested in event details.
+/// If no event is ready, returns 0.
+///
+/// Returns the raw `char32_t` code, which can be a synthesized `NCKEY_*`
+/// key, or `u32::MAX` on error. See [`NcEvent`][crate::NcEvent] for a
+/// decoded version.
+#[inline]
+pub fn ncdirect_getc_nblock(nc: &mut NcDirect, input: &mut NcInput) -> u32 {
+    unsafe {
+        let mut sigmask = NcSignalSet::full();
+        let ts = NcTime {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        ncdirect_getc(nc, &ts, sigmask.as_mut_ptr(), input)
+    }
+}
+
+/// 'input' may be NULL if the caller is uninterested in event details.
+/// Blocks until an event is processed or a signal is received.
+///
+/// Returns the raw `char32_t` code, which can be a synthesized `NCKEY_*`
+/// key, or `u32::MAX` on error. See [`NcEvent`][crate::NcEvent] for a
+/// decoded version.
+#[inline]
+pub fn ncdirect_getc_blocking(nc: &mut NcDirect, input: &mut NcInput) -> u32 {
+    unsafe {
+        let mut sigmask = NcSignalSet::new();
+        ncdirect_getc(nc, null(), sigmask.as_mut_ptr(), input)
+    }
+}
@@ -1,11 +1,158 @@
-//! `NcResult` for error handling
+//! `NcError` & `NcIntResult` for error handling
 
-/// `i32` value used to return errors, when value < 0, (usually -1).
+use core::{fmt, ptr::NonNull};
+use std::ffi::{CStr, CString};
+
+/// `i32` value used to return errors by the underlying C API.
+///
+/// A value < 0 means error, (usually -1).
 /// See also [`NCRESULT_OK`] and [`NCRESULT_ERR`].
-pub type NcResult = i32;
+pub type NcIntResult = i32;
 
-/// ERROR value, for the functions that return an [`NcResult`]
+/// OK value, for the functions that return an [`NcIntResult`]
 pub const NCRESULT_OK: i32 = 0;
 
-/// OK value, for the functions that return [`NcResult`]
+/// ERROR value, for the functions that return [`NcIntResult`]
 pub const NCRESULT_ERR: i32 = -1;
+
+/// The error type for the safe methods of this library.
+///
+/// It carries the name of the C function that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcError {
+    /// The function returned an error code.
+    ///
+    /// It's usually < [`NCRESULT_OK`], but it can be [`NCRESULT_OK`] for the
+    /// functions that return the count of what they did, when they failed
+    /// before doing anything.
+    Code {
+        /// The name of the function that failed.
+        func: &'static str,
+        /// The [`NcIntResult`] returned by the function.
+        code: NcIntResult,
+    },
+
+    /// The function returned a null pointer.
+    Null {
+        /// The name of the function that failed.
+        func: &'static str,
+    },
+
+    /// A string meant to be passed to the function contained a nul byte.
+    NulByte {
+        /// The name of the function that couldn't be called.
+        func: &'static str,
+    },
+}
+
+impl NcError {
+    /// Returns the name of the function that failed.
+    pub fn func(&self) -> &'static str {
+        match *self {
+            NcError::Code { func, .. } | NcError::Null { func } | NcError::NulByte { func } => func,
+        }
+    }
+
+    /// Returns the [`NcIntResult`] code, if there's any.
+    pub fn code(&self) -> Option<NcIntResult> {
+        match *self {
+            NcError::Code { code, .. } => Some(code),
+            NcError::Null { .. } | NcError::NulByte { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for NcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NcError::Code { func, code } => write!(f, "{}() returned error {}", func, code),
+            NcError::Null { func } => write!(f, "{}() returned NULL", func),
+            NcError::NulByte { func } => {
+                write!(f, "string passed to {}() contains a nul byte", func)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NcError {}
+
+impl From<NcError> for std::io::Error {
+    fn from(err: NcError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::Other, err)
+    }
+}
+
+/// The error returned when parsing a string into a type of this library fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NcParseError {
+    kind: &'static str,
+    input: String,
+}
+
+impl NcParseError {
+    /// New NcParseError, expects the name of the type that couldn't be parsed,
+    /// and the offending input.
+    pub fn new(kind: &'static str, input: &str) -> Self {
+        Self {
+            kind,
+            input: input.to_owned(),
+        }
+    }
+
+    /// Returns the name of the type that couldn't be parsed.
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Returns the offending input.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for NcParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {:?}", self.kind, self.input)
+    }
+}
+
+impl std::error::Error for NcParseError {}
+
+/// The result type for the safe methods of this library.
+pub type NcResult<T> = Result<T, NcError>;
+
+/// Converts the [`NcIntResult`] returned by `func` into an [`NcResult`],
+/// that contains the same value on success.
+#[inline]
+pub(crate) fn nc_result(func: &'static str, res: NcIntResult) -> NcResult<NcIntResult> {
+    if res < NCRESULT_OK {
+        Err(NcError::Code { func, code: res })
+    } else {
+        Ok(res)
+    }
+}
+
+/// Converts a `&str` into a `CString` that can be passed to `func`.
+#[inline]
+pub(crate) fn nc_cstring(func: &'static str, string: &str) -> NcResult<CString> {
+    CString::new(string).map_err(|_| NcError::NulByte { func })
+}
+
+/// Converts the pointer returned by `func` into an [`NcResult`],
+/// that contains the same pointer on success.
+#[inline]
+pub(crate) fn nc_ptr<T>(func: &'static str, ptr: *mut T) -> NcResult<NonNull<T>> {
+    NonNull::new(ptr).ok_or(NcError::Null { func })
+}
+
+/// Converts a static C string, like the names returned by the `*_str_*`
+/// functions, into a `&'static str`.
+///
+/// Returns an empty string if the pointer is NULL, or it's not valid UTF-8.
+#[inline]
+pub(crate) fn nc_static_str(ptr: *const libc::c_char) -> &'static str {
+    if ptr.is_null() {
+        return "";
+    }
+    unsafe { CStr::from_ptr(ptr) }.to_str().unwrap_or("")
+}
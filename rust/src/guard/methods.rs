@@ -0,0 +1,223 @@
+//! Guards methods and associated functions.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::{
+    error::nc_result, NcCursorGuard, NcDirect, NcDirectCursorGuard, NcDirectPushGuard,
+    NcMouseGuard, NcResult, Notcurses,
+};
+
+// Enables the cursor at `yx`, or disables it if `None`.
+fn set_cursor(nc: &mut Notcurses, yx: Option<(i32, i32)>) -> NcResult<()> {
+    let _lock = crate::pile::raster_lock();
+    match yx {
+        Some((y, x)) => nc_result("notcurses_cursor_enable", unsafe {
+            crate::notcurses_cursor_enable(nc, y, x)
+        })?,
+        None => nc_result("notcurses_cursor_disable", unsafe {
+            crate::notcurses_cursor_disable(nc)
+        })?,
+    };
+    Ok(())
+}
+
+// Shows or hides the cursor of a direct mode context.
+fn set_direct_cursor(ncd: &mut NcDirect, visible: bool) -> NcResult<()> {
+    if visible {
+        nc_result("ncdirect_cursor_enable", unsafe {
+            crate::ncdirect_cursor_enable(ncd)
+        })?;
+    } else {
+        nc_result("ncdirect_cursor_disable", unsafe {
+            crate::ncdirect_cursor_disable(ncd)
+        })?;
+    }
+    Ok(())
+}
+
+/// # `Notcurses` guards
+impl Notcurses {
+    /// Enables the terminal's cursor, if supported, placing it at `y`, `x`,
+    /// until the returned guard is dropped, which disables it again.
+    ///
+    /// It is an error if `y`, `x` lies outside the standard plane.
+    ///
+    /// The context stays borrowed by the guard, so any inner guard must be
+    /// created with [`NcCursorGuard::nest`].
+    pub fn cursor_guard(&mut self, y: i32, x: i32) -> NcResult<NcCursorGuard<'_>> {
+        set_cursor(self, Some((y, x)))?;
+        Ok(NcCursorGuard {
+            nc: self,
+            yx: Some((y, x)),
+            previous: None,
+        })
+    }
+
+    /// Enables mouse reporting until the returned guard is dropped,
+    /// which disables it again.
+    ///
+    /// Mouse events will be published to the input methods,
+    /// e.g. [`get_event_blocking`][Notcurses#method.get_event_blocking].
+    ///
+    /// The context stays borrowed by the guard, so any inner guard must be
+    /// created with [`NcMouseGuard::nest`].
+    pub fn mouse_guard(&mut self) -> NcResult<NcMouseGuard<'_>> {
+        let _lock = crate::pile::raster_lock();
+        nc_result("notcurses_mouse_enable", unsafe {
+            crate::notcurses_mouse_enable(self)
+        })?;
+        Ok(NcMouseGuard {
+            nc: self,
+            previous: false,
+        })
+    }
+}
+
+/// # `NcCursorGuard` Methods
+impl<'a> NcCursorGuard<'a> {
+    /// Moves the cursor to `y`, `x`.
+    pub fn move_yx(&mut self, y: i32, x: i32) -> NcResult<()> {
+        set_cursor(self.nc, Some((y, x)))?;
+        self.yx = Some((y, x));
+        Ok(())
+    }
+
+    /// Returns the position of the cursor, or `None` if it's disabled.
+    pub fn yx(&self) -> Option<(i32, i32)> {
+        self.yx
+    }
+
+    /// Enables the cursor at `y`, `x`, or disables it if `None`, until the
+    /// returned guard is dropped, which restores the state of this guard.
+    pub fn nest(&mut self, yx: Option<(i32, i32)>) -> NcResult<NcCursorGuard<'_>> {
+        set_cursor(self.nc, yx)?;
+        Ok(NcCursorGuard {
+            nc: &mut *self.nc,
+            yx,
+            previous: self.yx,
+        })
+    }
+}
+
+impl<'a> Drop for NcCursorGuard<'a> {
+    fn drop(&mut self) {
+        let _ = set_cursor(self.nc, self.previous);
+    }
+}
+
+impl<'a> Deref for NcCursorGuard<'a> {
+    type Target = Notcurses;
+    fn deref(&self) -> &Notcurses {
+        self.nc
+    }
+}
+
+/// # `NcMouseGuard` Methods
+impl<'a> NcMouseGuard<'a> {
+    /// Returns a guard that keeps mouse reporting enabled, and leaves it
+    /// enabled when dropped, since this guard is still alive.
+    pub fn nest(&mut self) -> NcMouseGuard<'_> {
+        NcMouseGuard {
+            nc: &mut *self.nc,
+            previous: true,
+        }
+    }
+}
+
+impl<'a> Drop for NcMouseGuard<'a> {
+    fn drop(&mut self) {
+        if !self.previous {
+            let _lock = crate::pile::raster_lock();
+            let _ = unsafe { crate::notcurses_mouse_disable(self.nc) };
+        }
+    }
+}
+
+impl<'a> Deref for NcMouseGuard<'a> {
+    type Target = Notcurses;
+    fn deref(&self) -> &Notcurses {
+        self.nc
+    }
+}
+
+/// # `NcDirect` guards
+impl NcDirect {
+    /// Shows or hides the cursor until the returned guard is dropped,
+    /// which shows it again.
+    pub fn cursor_guard(&mut self, visible: bool) -> NcResult<NcDirectCursorGuard<'_>> {
+        set_direct_cursor(self, visible)?;
+        Ok(NcDirectCursorGuard {
+            ncd: self,
+            visible,
+            previous: true,
+        })
+    }
+
+    /// Pushes the cursor location to the terminal's stack, and pops it back
+    /// when the returned guard is dropped.
+    ///
+    /// The depth of this stack, and indeed its existence, is terminal-dependent.
+    pub fn cursor_push(&mut self) -> NcResult<NcDirectPushGuard<'_>> {
+        nc_result("ncdirect_cursor_push", unsafe {
+            crate::ncdirect_cursor_push(self)
+        })?;
+        Ok(NcDirectPushGuard { ncd: self })
+    }
+}
+
+/// # `NcDirectCursorGuard` Methods
+impl<'a> NcDirectCursorGuard<'a> {
+    /// Returns whether the cursor is visible.
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows or hides the cursor until the returned guard is dropped,
+    /// which restores the visibility of this guard.
+    pub fn nest(&mut self, visible: bool) -> NcResult<NcDirectCursorGuard<'_>> {
+        set_direct_cursor(self.ncd, visible)?;
+        Ok(NcDirectCursorGuard {
+            ncd: self.ncd,
+            visible,
+            previous: self.visible,
+        })
+    }
+}
+
+impl<'a> Drop for NcDirectCursorGuard<'a> {
+    fn drop(&mut self) {
+        let _ = set_direct_cursor(self.ncd, self.previous);
+    }
+}
+
+impl<'a> Deref for NcDirectCursorGuard<'a> {
+    type Target = NcDirect;
+    fn deref(&self) -> &NcDirect {
+        self.ncd
+    }
+}
+
+impl<'a> DerefMut for NcDirectCursorGuard<'a> {
+    fn deref_mut(&mut self) -> &mut NcDirect {
+        self.ncd
+    }
+}
+
+impl<'a> Drop for NcDirectPushGuard<'a> {
+    fn drop(&mut self) {
+        let _ = unsafe { crate::ncdirect_cursor_pop(self.ncd) };
+    }
+}
+
+impl<'a> Deref for NcDirectPushGuard<'a> {
+    type Target = NcDirect;
+    fn deref(&self) -> &NcDirect {
+        self.ncd
+    }
+}
+
+impl<'a> DerefMut for NcDirectPushGuard<'a> {
+    fn deref_mut(&mut self) -> &mut NcDirect {
+        self.ncd
+    }
+}
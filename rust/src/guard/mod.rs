@@ -0,0 +1,80 @@
+//! Scoped guards for the cursor & the mouse.
+
+// functions already exported by bindgen : 8
+// ------------------------------------------
+// (W) wrap: 8 / 0
+// (#) test: 0 / 8
+// ------------------------------------------
+//W ncdirect_cursor_disable
+//W ncdirect_cursor_enable
+//W ncdirect_cursor_pop
+//W ncdirect_cursor_push
+//W notcurses_cursor_disable
+//W notcurses_cursor_enable
+//W notcurses_mouse_disable
+//W notcurses_mouse_enable
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+use crate::{NcDirect, Notcurses};
+
+/// A guard that keeps the terminal cursor visible at a position,
+/// and restores its previous state when dropped.
+///
+/// Created by [`Notcurses::cursor_guard`], which assumes the cursor
+/// was disabled (the default in full mode), or by [`NcCursorGuard::nest`],
+/// which restores the position of the outer guard.
+///
+/// It dereferences to the `Notcurses` context, but only immutably, so that
+/// no other outer guard can be created while it's alive.
+#[derive(Debug)]
+pub struct NcCursorGuard<'a> {
+    pub(crate) nc: &'a mut Notcurses,
+    // the position of the cursor, or None if disabled.
+    pub(crate) yx: Option<(i32, i32)>,
+    pub(crate) previous: Option<(i32, i32)>,
+}
+
+/// A guard that keeps mouse reporting enabled,
+/// and restores its previous state when dropped.
+///
+/// Created by [`Notcurses::mouse_guard`], which assumes mouse reporting
+/// was disabled (the default), or by [`NcMouseGuard::nest`],
+/// which leaves it enabled.
+///
+/// It dereferences to the `Notcurses` context, but only immutably, so that
+/// no other outer guard can be created while it's alive.
+#[derive(Debug)]
+pub struct NcMouseGuard<'a> {
+    pub(crate) nc: &'a mut Notcurses,
+    pub(crate) previous: bool,
+}
+
+/// A guard that shows or hides the cursor of an [`NcDirect`] context,
+/// and restores its previous visibility when dropped.
+///
+/// Created by [`NcDirect::cursor_guard`], which assumes the cursor was
+/// visible (the default in direct mode), or by [`NcDirectCursorGuard::nest`],
+/// which restores the visibility of the outer guard.
+///
+/// It dereferences to the `NcDirect` context.
+#[derive(Debug)]
+pub struct NcDirectCursorGuard<'a> {
+    pub(crate) ncd: &'a mut NcDirect,
+    pub(crate) visible: bool,
+    pub(crate) previous: bool,
+}
+
+/// A guard that pushes the cursor location of an [`NcDirect`] context
+/// to the terminal's stack, and pops it back when dropped.
+///
+/// Created by [`NcDirect::cursor_push`].
+///
+/// It dereferences to the `NcDirect` context, so the guards can be nested.
+#[derive(Debug)]
+pub struct NcDirectPushGuard<'a> {
+    pub(crate) ncd: &'a mut NcDirect,
+}
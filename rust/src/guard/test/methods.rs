@@ -0,0 +1,33 @@
+//! Test the guards methods.
+
+use serial_test::serial;
+
+use crate::FullMode;
+
+#[test]
+#[serial]
+fn nccursorguard() {
+    let mut nc = FullMode::new().unwrap();
+    {
+        let mut cursor = nc.cursor_guard(0, 0).unwrap();
+        assert_eq![Some((0, 0)), cursor.yx()];
+        {
+            let hidden = cursor.nest(None).unwrap();
+            assert_eq![None, hidden.yx()];
+        }
+        cursor.move_yx(1, 1).unwrap();
+        assert_eq![Some((1, 1)), cursor.yx()];
+        cursor.stdplane().putstr_yx(0, 0, "hello").unwrap();
+        cursor.render().unwrap();
+    }
+    // outside the standard plane
+    assert![nc.cursor_guard(-1, -1).is_err()];
+}
+
+#[test]
+#[serial]
+fn ncmouseguard() {
+    let mut nc = FullMode::new().unwrap();
+    let mut mouse = nc.mouse_guard().unwrap();
+    let _nested = mouse.nest();
+}
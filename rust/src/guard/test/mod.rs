@@ -0,0 +1,4 @@
+//! Guards tests
+
+#[cfg(test)]
+mod methods;
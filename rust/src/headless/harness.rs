@@ -0,0 +1,247 @@
+//! `NcHeadless` methods and associated functions.
+
+use core::{
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+use std::{
+    ffi::CStr,
+    os::unix::io::RawFd,
+    sync::{Arc, Condvar, Mutex},
+    thread,
+    time::Instant,
+};
+
+use crate::{
+    error::{nc_cstring, nc_ptr, nc_result},
+    notcurses_init, FullMode, NcHeadless, NcResult, NcScreen, NotcursesBuilder,
+    NCHEADLESS_TERMTYPE,
+};
+
+use super::NcHeadlessOutput;
+
+// How long to wait for the output to be captured before panicking.
+const SYNC_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// # `NcHeadless` Constructors
+impl NcHeadless {
+    /// New NcHeadless, expects the size of the terminal.
+    ///
+    /// Uses the [`NCHEADLESS_TERMTYPE`] terminal type, whose terminfo entry
+    /// must be installed.
+    pub fn new(rows: u16, cols: u16) -> NcResult<Self> {
+        Self::with_termtype(rows, cols, NCHEADLESS_TERMTYPE)
+    }
+
+    /// New NcHeadless, expects the size and the type of the terminal.
+    pub fn with_termtype(rows: u16, cols: u16, termtype: &str) -> NcResult<Self> {
+        let termtype = nc_cstring("notcurses_init", termtype)?;
+        let (master, slave) = unsafe { open_pty(rows, cols)? };
+
+        let output = Arc::new((Mutex::new(NcHeadlessOutput::default()), Condvar::new()));
+        let reader = {
+            let output = output.clone();
+            thread::spawn(move || read_pty(master, &output))
+        };
+
+        let mut term = Self {
+            nc: None,
+            screen: NcScreen::new(rows as u32, cols as u32),
+            master,
+            slave,
+            output,
+            reader: Some(reader),
+            syncs: 0,
+        };
+
+        let mut options = NotcursesBuilder::new()
+            .suppress_banners(true)
+            .no_quit_sighandlers(true)
+            .no_winch_sighandler(true)
+            .options();
+        options.termtype = termtype.as_ptr();
+        let nc = nc_ptr("notcurses_init", unsafe {
+            notcurses_init(&options, term.slave as *mut _)
+        })?;
+        term.nc = Some(unsafe { FullMode::from_raw(&mut *nc.as_ptr()) });
+        term.sync();
+        Ok(term)
+    }
+}
+
+/// # `NcHeadless` Methods
+impl NcHeadless {
+    /// Renders and rasterizes the standard pile, and updates the screen.
+    pub fn render(&mut self) -> NcResult<()> {
+        self.full_mode().render()?;
+        self.sync();
+        Ok(())
+    }
+
+    /// Returns the screen, as of the last [`render`][NcHeadless#method.render]
+    /// or [`sync`][NcHeadless#method.sync].
+    pub fn screen(&self) -> &NcScreen {
+        &self.screen
+    }
+
+    /// Updates the screen with all the output written to the terminal so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output can't be captured in a few seconds.
+    pub fn sync(&mut self) {
+        // a private OSC sequence, that marks the end of the current output
+        self.syncs += 1;
+        let marker = format!["\x1b]9999;ncheadless-{}\x07", self.syncs];
+        unsafe {
+            libc::fflush(self.slave);
+            libc::write(
+                libc::fileno(self.slave),
+                marker.as_ptr() as *const _,
+                marker.len(),
+            );
+        }
+
+        let (lock, cvar) = &*self.output;
+        let deadline = Instant::now() + SYNC_TIMEOUT;
+        let mut output = lock.lock().unwrap();
+        loop {
+            if let Some(pos) = find(&output.bytes, marker.as_bytes()) {
+                let bytes: Vec<u8> = output.bytes.drain(..pos + marker.len()).collect();
+                self.screen.feed(&bytes[..pos]);
+                return;
+            }
+            let now = Instant::now();
+            if output.closed || now >= deadline {
+                panic!["NcHeadless: couldn't capture the terminal output"];
+            }
+            output = cvar.wait_timeout(output, deadline - now).unwrap().0;
+        }
+    }
+
+    /// Returns the [`FullMode`] context.
+    pub fn full_mode(&mut self) -> &mut FullMode {
+        self.nc.as_mut().expect("context already stopped")
+    }
+}
+
+impl Drop for NcHeadless {
+    fn drop(&mut self) {
+        // stop the context while the terminal is still open
+        drop(self.nc.take());
+        self.output.0.lock().unwrap().stop = true;
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+        unsafe {
+            libc::fclose(self.slave);
+            libc::close(self.master);
+        }
+    }
+}
+
+impl Deref for NcHeadless {
+    type Target = FullMode;
+
+    fn deref(&self) -> &FullMode {
+        self.nc.as_ref().expect("context already stopped")
+    }
+}
+
+impl DerefMut for NcHeadless {
+    fn deref_mut(&mut self) -> &mut FullMode {
+        self.full_mode()
+    }
+}
+
+// Opens a pseudo-terminal in raw mode with the provided size.
+//
+// Returns the master file descriptor and the slave stream.
+unsafe fn open_pty(rows: u16, cols: u16) -> NcResult<(RawFd, *mut libc::FILE)> {
+    let master = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+    nc_result("posix_openpt", master)?;
+
+    let slave = (|| {
+        nc_result("grantpt", libc::grantpt(master))?;
+        nc_result("unlockpt", libc::unlockpt(master))?;
+        let mut name = [0 as libc::c_char; 128];
+        // it returns the error number instead of -1
+        nc_result(
+            "ptsname_r",
+            -libc::ptsname_r(master, name.as_mut_ptr(), name.len()),
+        )?;
+        let slave = libc::open(
+            CStr::from_ptr(name.as_ptr()).as_ptr(),
+            libc::O_RDWR | libc::O_NOCTTY,
+        );
+        nc_result("open", slave)?;
+
+        let size = libc::winsize {
+            ws_row: rows,
+            ws_col: cols,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+        let mut termios = core::mem::zeroed();
+        let configured = nc_result("ioctl", libc::ioctl(slave, libc::TIOCSWINSZ, &size))
+            .and_then(|_| nc_result("tcgetattr", libc::tcgetattr(slave, &mut termios)))
+            .and_then(|_| {
+                // no output processing, so "\n" is not translated into "\r\n"
+                libc::cfmakeraw(&mut termios);
+                nc_result("tcsetattr", libc::tcsetattr(slave, libc::TCSANOW, &termios))
+            });
+        let stream = libc::fdopen(slave, "w\0".as_ptr() as *const _);
+        if let Err(err) = configured.and_then(|_| nc_ptr("fdopen", stream)) {
+            libc::close(slave);
+            return Err(err);
+        }
+        Ok(stream)
+    })();
+
+    match slave {
+        Ok(slave) => Ok((master, slave)),
+        Err(err) => {
+            libc::close(master);
+            Err(err)
+        }
+    }
+}
+
+// Reads everything from the pseudo-terminal master, until the terminal is
+// closed or it's asked to stop.
+fn read_pty(master: RawFd, output: &(Mutex<NcHeadlessOutput>, Condvar)) {
+    let (lock, cvar) = output;
+    let mut buf = [0u8; 4096];
+    loop {
+        if lock.lock().unwrap().stop {
+            return;
+        }
+        let mut pfd = libc::pollfd {
+            fd: master,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        if unsafe { libc::poll(&mut pfd, 1, 20) } <= 0 {
+            continue;
+        }
+        let len = unsafe { libc::read(master, buf.as_mut_ptr() as *mut _, buf.len()) };
+        let interrupted =
+            len < 0 && std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted;
+        let mut output = lock.lock().unwrap();
+        if len > 0 {
+            output.bytes.extend_from_slice(&buf[..len as usize]);
+        } else if !interrupted {
+            output.closed = true;
+        }
+        cvar.notify_all();
+        if output.closed {
+            return;
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
@@ -0,0 +1,106 @@
+//! `NcHeadless` & `NcScreen`, for testing without a terminal.
+//!
+//! Available with the `headless` feature.
+//!
+//! [`NcHeadless`] starts a [`FullMode`] context on a pseudo-terminal, with a
+//! fixed terminal type and size, and captures everything it writes into an
+//! [`NcScreen`], a grid of cells updated by parsing the escape sequences.
+//!
+//! ```no_run
+//! use libnotcurses_sys::*;
+//!
+//! # fn main() -> NcResult<()> {
+//! let mut term = NcHeadless::new(10, 40)?;
+//! term.stdplane().putstr_yx(2, 3, "hello")?;
+//! term.render()?;
+//! assert_eq!["hello", term.screen().row_text(2).trim()];
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(test)]
+mod test;
+
+mod harness;
+mod screen;
+
+use std::{
+    os::unix::io::RawFd,
+    sync::{Arc, Condvar, Mutex},
+    thread::JoinHandle,
+};
+
+use crate::{FullMode, NcRgb, NcStyleMask};
+
+/// The terminal type used by [`NcHeadless`] by default.
+pub const NCHEADLESS_TERMTYPE: &str = "xterm-256color";
+
+/// A cell of an [`NcScreen`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NcScreenCell {
+    /// The grapheme cluster in the cell.
+    ///
+    /// It's empty for the second column of a wide glyph.
+    pub glyph: String,
+    /// The foreground color, or `None` for the default one.
+    pub fg: Option<NcRgb>,
+    /// The background color, or `None` for the default one.
+    pub bg: Option<NcRgb>,
+    /// The `NCSTYLE_*` styles.
+    pub styles: NcStyleMask,
+}
+
+/// A virtual terminal screen, a grid of [`NcScreenCell`]s updated by
+/// parsing the output of a terminal program.
+///
+/// It understands the subset of xterm escape sequences that notcurses
+/// emits: cursor movement, erasing, and the SGR styles and colors.
+/// Other sequences are ignored.
+#[derive(Debug, Clone)]
+pub struct NcScreen {
+    rows: u32,
+    cols: u32,
+    cells: Vec<NcScreenCell>,
+
+    // cursor position, `x` can be `cols` when a wrap is pending
+    y: u32,
+    x: u32,
+    saved: (u32, u32),
+
+    // the attributes for the new cells
+    pen: NcScreenCell,
+
+    // the bytes of an incomplete sequence or UTF-8 character
+    pending: Vec<u8>,
+}
+
+/// A [`FullMode`] context running on a pseudo-terminal, for testing.
+///
+/// Its output is captured into an [`NcScreen`], which is updated every time
+/// the context is rendered through [`render`][NcHeadless#method.render].
+///
+/// It dereferences to [`FullMode`], and therefore to [`Notcurses`][crate::Notcurses].
+#[derive(Debug)]
+pub struct NcHeadless {
+    // it's an Option just so it can be stopped before closing the terminal
+    nc: Option<FullMode>,
+    screen: NcScreen,
+
+    // the pseudo-terminal
+    master: RawFd,
+    slave: *mut libc::FILE,
+
+    // the output read from the master, and its reader thread
+    output: Arc<(Mutex<NcHeadlessOutput>, Condvar)>,
+    reader: Option<JoinHandle<()>>,
+    syncs: u64,
+}
+
+#[derive(Debug, Default)]
+struct NcHeadlessOutput {
+    bytes: Vec<u8>,
+    // set by the reader thread when the terminal is closed
+    closed: bool,
+    // set to ask the reader thread to finish
+    stop: bool,
+}
@@ -0,0 +1,583 @@
+//! `NcScreen` methods and associated functions.
+
+use core::fmt;
+
+use crate::{
+    NcRgb, NcScreen, NcScreenCell, NCSTYLE_BLINK, NCSTYLE_BOLD, NCSTYLE_DIM, NCSTYLE_INVIS,
+    NCSTYLE_ITALIC, NCSTYLE_REVERSE, NCSTYLE_STRUCK, NCSTYLE_UNDERLINE,
+};
+
+/// # `NcScreenCell` Constructors
+impl NcScreenCell {
+    /// New blank NcScreenCell, with default colors and no styles.
+    pub fn new() -> Self {
+        Self {
+            glyph: " ".to_owned(),
+            fg: None,
+            bg: None,
+            styles: 0,
+        }
+    }
+}
+
+impl Default for NcScreenCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # `NcScreen` Constructors
+impl NcScreen {
+    /// New blank NcScreen, expects its size.
+    ///
+    /// A screen without rows or columns ignores everything fed to it.
+    pub fn new(rows: u32, cols: u32) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![NcScreenCell::new(); (rows * cols) as usize],
+            y: 0,
+            x: 0,
+            saved: (0, 0),
+            pen: NcScreenCell::new(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+/// # `NcScreen` Methods
+impl NcScreen {
+    /// Returns the number of rows.
+    pub fn rows(&self) -> u32 {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> u32 {
+        self.cols
+    }
+
+    /// Returns the cursor position, as `(y, x)`.
+    pub fn cursor_yx(&self) -> (u32, u32) {
+        (self.y, self.x.min(self.cols.saturating_sub(1)))
+    }
+
+    /// Returns the cell at `y`, `x`, if it's inside the screen.
+    pub fn cell(&self, y: u32, x: u32) -> Option<&NcScreenCell> {
+        if y < self.rows && x < self.cols {
+            Some(&self.cells[(y * self.cols + x) as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the text of the row `y`, with one glyph per column,
+    /// or an empty string if it's outside the screen.
+    pub fn row_text(&self, y: u32) -> String {
+        (0..self.cols)
+            .filter_map(|x| self.cell(y, x))
+            .map(|cell| cell.glyph.as_str())
+            .collect()
+    }
+
+    /// Returns the text of the whole screen, a line per row, without the
+    /// trailing whitespace of each row.
+    pub fn text(&self) -> String {
+        (0..self.rows)
+            .map(|y| self.row_text(y).trim_end().to_owned())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Does any row of the screen contain `text`?
+    pub fn contains(&self, text: &str) -> bool {
+        (0..self.rows).any(|y| self.row_text(y).contains(text))
+    }
+
+    /// Returns the position of the first occurrence of `text`, as `(y, x)`.
+    pub fn find(&self, text: &str) -> Option<(u32, u32)> {
+        (0..self.rows).find_map(|y| {
+            let row = self.row_text(y);
+            let byte = row.find(text)?;
+            // convert the byte offset into a column, skipping the empty
+            // glyphs of wide characters, which take no bytes in the row
+            let mut offset = 0;
+            for x in 0..self.cols {
+                let glyph = &self.cells[(y * self.cols + x) as usize].glyph;
+                if offset == byte && !glyph.is_empty() {
+                    return Some((y, x));
+                }
+                offset += glyph.len();
+            }
+            None
+        })
+    }
+
+    /// Parses the output of a terminal program, updating the screen.
+    ///
+    /// Incomplete sequences at the end are kept until the next call.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        if self.cells.is_empty() {
+            return;
+        }
+        let mut input = core::mem::take(&mut self.pending);
+        input.extend_from_slice(bytes);
+
+        let mut i = 0;
+        while i < input.len() {
+            match self.parse(&input[i..]) {
+                Some(len) => i += len,
+                None => {
+                    self.pending = input[i..].to_vec();
+                    return;
+                }
+            }
+        }
+    }
+
+    // private methods --
+
+    // Parses a control, sequence or character at the start of `bytes`.
+    //
+    // Returns the number of bytes consumed, or `None` if it's incomplete.
+    fn parse(&mut self, bytes: &[u8]) -> Option<usize> {
+        match bytes[0] {
+            0x1b => self.parse_escape(bytes),
+            b'\r' => {
+                self.x = 0;
+                Some(1)
+            }
+            b'\n' | 0x0b | 0x0c => {
+                self.linefeed();
+                Some(1)
+            }
+            0x08 => {
+                self.x = self.x.min(self.cols.saturating_sub(1)).saturating_sub(1);
+                Some(1)
+            }
+            b'\t' => {
+                self.x = ((self.x / 8 + 1) * 8).min(self.cols.saturating_sub(1));
+                Some(1)
+            }
+            0..=0x1f | 0x7f => Some(1),
+            _ => {
+                let len = utf8_len(bytes[0]);
+                if bytes.len() < len {
+                    return None;
+                }
+                match core::str::from_utf8(&bytes[..len]) {
+                    Ok(s) => self.print(s.chars().next().unwrap_or(' ')),
+                    Err(_) => self.print(char::REPLACEMENT_CHARACTER),
+                }
+                Some(len)
+            }
+        }
+    }
+
+    fn parse_escape(&mut self, bytes: &[u8]) -> Option<usize> {
+        match *bytes.get(1)? {
+            b'[' => self.parse_csi(bytes),
+            // OSC, DCS, APC, PM & SOS strings, ended by BEL or ST
+            b']' | b'P' | b'_' | b'^' | b'X' => {
+                let mut i = 2;
+                loop {
+                    match *bytes.get(i)? {
+                        0x07 => return Some(i + 1),
+                        0x1b if *bytes.get(i + 1)? == b'\\' => return Some(i + 2),
+                        _ => i += 1,
+                    }
+                }
+            }
+            // character set designations
+            b'(' | b')' | b'*' | b'+' | b'#' | b'%' => {
+                bytes.get(2)?;
+                Some(3)
+            }
+            b'7' => {
+                self.saved = (self.y, self.x);
+                Some(2)
+            }
+            b'8' => {
+                let (y, x) = self.saved;
+                self.move_to(y, x);
+                Some(2)
+            }
+            b'c' => {
+                *self = NcScreen::new(self.rows, self.cols);
+                Some(2)
+            }
+            b'D' => {
+                self.linefeed();
+                Some(2)
+            }
+            b'E' => {
+                self.x = 0;
+                self.linefeed();
+                Some(2)
+            }
+            b'M' => {
+                if self.y == 0 {
+                    self.scroll_down();
+                } else {
+                    self.y -= 1;
+                }
+                Some(2)
+            }
+            _ => Some(2),
+        }
+    }
+
+    fn parse_csi(&mut self, bytes: &[u8]) -> Option<usize> {
+        // ESC [ private? params intermediates final
+        let mut i = 2;
+        let private = match *bytes.get(i)? {
+            b @ b'<'..=b'?' => {
+                i += 1;
+                Some(b)
+            }
+            _ => None,
+        };
+        let start = i;
+        while bytes.get(i)?.is_ascii_digit() || *bytes.get(i)? == b';' || *bytes.get(i)? == b':' {
+            i += 1;
+        }
+        let params = parse_params(&bytes[start..i]);
+        while (0x20..=0x2f).contains(bytes.get(i)?) {
+            i += 1;
+        }
+        let fin = *bytes.get(i)?;
+        if private.is_none() {
+            self.csi(fin, &params);
+        } else if private == Some(b'?') && (fin == b'h' || fin == b'l') {
+            // the alternate screen is always cleared when switching
+            if params
+                .iter()
+                .any(|p| p[0] == 1049 || p[0] == 47 || p[0] == 1047)
+            {
+                self.erase(0, self.rows * self.cols);
+            }
+        }
+        Some(i + 1)
+    }
+
+    fn csi(&mut self, fin: u8, params: &[Vec<u32>]) {
+        // the first value of the parameter `n`, or `default` if it's 0
+        let arg = |n: usize, default: u32| match params.get(n).map(|p| p[0]) {
+            Some(0) | None => default,
+            Some(v) => v,
+        };
+        match fin {
+            b'H' | b'f' => self.move_to(arg(0, 1) - 1, arg(1, 1) - 1),
+            b'A' => self.move_to(self.y.saturating_sub(arg(0, 1)), self.x),
+            b'B' | b'e' => self.move_to(self.y + arg(0, 1), self.x),
+            b'C' | b'a' => self.move_to(self.y, self.x + arg(0, 1)),
+            b'D' => self.move_to(self.y, self.x.saturating_sub(arg(0, 1))),
+            b'E' => self.move_to(self.y + arg(0, 1), 0),
+            b'F' => self.move_to(self.y.saturating_sub(arg(0, 1)), 0),
+            b'G' | b'`' => self.move_to(self.y, arg(0, 1) - 1),
+            b'd' => self.move_to(arg(0, 1) - 1, self.x),
+            b'J' => {
+                let cursor = self.y * self.cols + self.x.min(self.cols);
+                match params.first().map_or(0, |p| p[0]) {
+                    0 => self.erase(cursor, self.rows * self.cols),
+                    1 => self.erase(0, (cursor + 1).min(self.rows * self.cols)),
+                    _ => self.erase(0, self.rows * self.cols),
+                }
+            }
+            b'K' => {
+                let row = self.y * self.cols;
+                let cursor = row + self.x.min(self.cols);
+                match params.first().map_or(0, |p| p[0]) {
+                    0 => self.erase(cursor, row + self.cols),
+                    1 => self.erase(row, (cursor + 1).min(row + self.cols)),
+                    _ => self.erase(row, row + self.cols),
+                }
+            }
+            b'X' => {
+                let cursor = self.y * self.cols + self.x.min(self.cols);
+                let end = (cursor + arg(0, 1)).min(self.y * self.cols + self.cols);
+                self.erase(cursor, end);
+            }
+            b'm' => self.sgr(params),
+            b's' => self.saved = (self.y, self.x),
+            b'u' => {
+                let (y, x) = self.saved;
+                self.move_to(y, x);
+            }
+            _ => (),
+        }
+    }
+
+    fn sgr(&mut self, params: &[Vec<u32>]) {
+        if params.is_empty() {
+            self.pen = NcScreenCell::new();
+            return;
+        }
+        let mut i = 0;
+        while i < params.len() {
+            let p = &params[i];
+            match p[0] {
+                0 => self.pen = NcScreenCell::new(),
+                1 => self.pen.styles |= NCSTYLE_BOLD,
+                2 => self.pen.styles |= NCSTYLE_DIM,
+                3 => self.pen.styles |= NCSTYLE_ITALIC,
+                4 => self.pen.styles |= NCSTYLE_UNDERLINE,
+                5 | 6 => self.pen.styles |= NCSTYLE_BLINK,
+                7 => self.pen.styles |= NCSTYLE_REVERSE,
+                8 => self.pen.styles |= NCSTYLE_INVIS,
+                9 => self.pen.styles |= NCSTYLE_STRUCK,
+                21 | 22 => self.pen.styles &= !(NCSTYLE_BOLD | NCSTYLE_DIM),
+                23 => self.pen.styles &= !NCSTYLE_ITALIC,
+                24 => self.pen.styles &= !NCSTYLE_UNDERLINE,
+                25 => self.pen.styles &= !NCSTYLE_BLINK,
+                27 => self.pen.styles &= !NCSTYLE_REVERSE,
+                28 => self.pen.styles &= !NCSTYLE_INVIS,
+                29 => self.pen.styles &= !NCSTYLE_STRUCK,
+                n @ 30..=37 => self.pen.fg = Some(palette_rgb(n - 30)),
+                n @ 40..=47 => self.pen.bg = Some(palette_rgb(n - 40)),
+                n @ 90..=97 => self.pen.fg = Some(palette_rgb(n - 90 + 8)),
+                n @ 100..=107 => self.pen.bg = Some(palette_rgb(n - 100 + 8)),
+                39 => self.pen.fg = None,
+                49 => self.pen.bg = None,
+                n @ 38 | n @ 48 => {
+                    // the color is either in this parameter's subparameters
+                    // (38:2:r:g:b), or in the next parameters (38;2;r;g;b)
+                    let (values, used) = if p.len() > 1 {
+                        (p[1..].to_vec(), 0)
+                    } else {
+                        let rest: Vec<u32> = params[i + 1..].iter().map(|p| p[0]).collect();
+                        let used = match rest.first() {
+                            Some(2) => 4,
+                            Some(5) => 2,
+                            _ => 0,
+                        };
+                        (rest, used)
+                    };
+                    let color = match values.as_slice() {
+                        // 38:2::r:g:b includes an empty colorspace id
+                        [2, _, r, g, b] if p.len() > 5 => Some(rgb(*r, *g, *b)),
+                        [2, r, g, b, ..] => Some(rgb(*r, *g, *b)),
+                        [5, idx, ..] => Some(palette_rgb(*idx)),
+                        _ => None,
+                    };
+                    if n == 38 {
+                        self.pen.fg = color;
+                    } else {
+                        self.pen.bg = color;
+                    }
+                    i += used;
+                }
+                _ => (),
+            }
+            i += 1;
+        }
+    }
+
+    fn print(&mut self, ch: char) {
+        let mut ch = ch;
+        let mut width = char_width(ch);
+        if width == 0 {
+            // combining characters join the previous cell
+            let x = self.x.min(self.cols).saturating_sub(1);
+            let idx = (self.y * self.cols + x) as usize;
+            if let Some(cell) = self.cells.get_mut(idx) {
+                cell.glyph.push(ch);
+            }
+            return;
+        }
+        if width > self.cols {
+            // a wide glyph that can't fit in a row at all
+            ch = char::REPLACEMENT_CHARACTER;
+            width = 1;
+        }
+        if self.x + width > self.cols {
+            self.x = 0;
+            self.linefeed();
+        }
+        let idx = (self.y * self.cols + self.x) as usize;
+        let mut cell = self.pen.clone();
+        cell.glyph = ch.to_string();
+        self.cells[idx] = cell;
+        if width == 2 {
+            let mut cell = self.pen.clone();
+            cell.glyph = String::new();
+            self.cells[idx + 1] = cell;
+        }
+        self.x += width;
+    }
+
+    fn move_to(&mut self, y: u32, x: u32) {
+        self.y = y.min(self.rows.saturating_sub(1));
+        self.x = x.min(self.cols.saturating_sub(1));
+    }
+
+    fn linefeed(&mut self) {
+        if self.y + 1 >= self.rows {
+            self.scroll_up();
+        } else {
+            self.y += 1;
+        }
+    }
+
+    fn scroll_up(&mut self) {
+        let cols = self.cols as usize;
+        self.cells.drain(..cols);
+        let blank = self.blank();
+        self.cells.extend(vec![blank; cols]);
+    }
+
+    fn scroll_down(&mut self) {
+        let cols = self.cols as usize;
+        let len = self.cells.len();
+        self.cells.truncate(len - cols);
+        let blank = self.blank();
+        self.cells.splice(..0, vec![blank; cols]);
+    }
+
+    // Erases the cells between the indexes `from` and `to`, with the
+    // current background color.
+    fn erase(&mut self, from: u32, to: u32) {
+        let blank = self.blank();
+        for cell in &mut self.cells[from as usize..to as usize] {
+            *cell = blank.clone();
+        }
+    }
+
+    fn blank(&self) -> NcScreenCell {
+        NcScreenCell {
+            bg: self.pen.bg,
+            ..NcScreenCell::new()
+        }
+    }
+}
+
+impl fmt::Display for NcScreen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text())
+    }
+}
+
+// Splits the CSI parameters by ';', and their subparameters by ':'.
+fn parse_params(bytes: &[u8]) -> Vec<Vec<u32>> {
+    if bytes.is_empty() {
+        return Vec::new();
+    }
+    bytes
+        .split(|b| *b == b';')
+        .map(|param| {
+            param
+                .split(|b| *b == b':')
+                .map(|sub| {
+                    sub.iter().fold(0u32, |n, d| {
+                        n.saturating_mul(10).saturating_add((d - b'0') as u32)
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn utf8_len(first: u8) -> usize {
+    match first {
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => 1,
+    }
+}
+
+fn rgb(r: u32, g: u32, b: u32) -> NcRgb {
+    (r.min(255) << 16) | (g.min(255) << 8) | b.min(255)
+}
+
+// Returns the RGB of an entry of the xterm 256 color palette.
+fn palette_rgb(idx: u32) -> NcRgb {
+    const BASE: [NcRgb; 16] = [
+        0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+        0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+    ];
+    match idx {
+        0..=15 => BASE[idx as usize],
+        16..=231 => {
+            let level = |v: u32| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = idx - 16;
+            rgb(level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        _ => {
+            let gray = 8 + (idx.min(255) - 232) * 10;
+            rgb(gray, gray, gray)
+        }
+    }
+}
+
+// Returns the number of columns a character takes, approximating wcwidth().
+fn char_width(ch: char) -> u32 {
+    match ch as u32 {
+        0x0300..=0x036f
+        | 0x0483..=0x0489
+        | 0x0591..=0x05bd
+        | 0x0610..=0x061a
+        | 0x064b..=0x065f
+        | 0x200b..=0x200f
+        | 0x20d0..=0x20ff
+        | 0xfe00..=0xfe0f
+        | 0xfe20..=0xfe2f
+        | 0x1f3fb..=0x1f3ff
+        | 0xe0100..=0xe01ef => 0,
+        0x1100..=0x115f
+        | 0x231a..=0x231b
+        | 0x2329..=0x232a
+        | 0x23e9..=0x23ec
+        | 0x2614..=0x2615
+        | 0x2648..=0x2653
+        | 0x26aa..=0x26ab
+        | 0x26bd..=0x26be
+        | 0x26c4..=0x26c5
+        | 0x26f2..=0x26f5
+        | 0x2e80..=0x303e
+        | 0x3041..=0x33ff
+        | 0x3400..=0x4dbf
+        | 0x4e00..=0x9fff
+        | 0xa000..=0xa4cf
+        | 0xac00..=0xd7a3
+        | 0xf900..=0xfaff
+        | 0xfe30..=0xfe4f
+        | 0xff00..=0xff60
+        | 0xffe0..=0xffe6
+        | 0x1f004
+        | 0x1f0cf
+        | 0x1f18e
+        | 0x1f191..=0x1f19a
+        | 0x1f200..=0x1f251
+        | 0x1f300..=0x1f320
+        | 0x1f32d..=0x1f335
+        | 0x1f337..=0x1f37c
+        | 0x1f37e..=0x1f393
+        | 0x1f3a0..=0x1f3ca
+        | 0x1f3cf..=0x1f3d3
+        | 0x1f3e0..=0x1f3f0
+        | 0x1f3f4
+        | 0x1f3f8..=0x1f43e
+        | 0x1f440
+        | 0x1f442..=0x1f4fc
+        | 0x1f4ff..=0x1f53d
+        | 0x1f54b..=0x1f54e
+        | 0x1f550..=0x1f567
+        | 0x1f57a
+        | 0x1f595..=0x1f596
+        | 0x1f5a4
+        | 0x1f5fb..=0x1f64f
+        | 0x1f680..=0x1f6c5
+        | 0x1f6cc
+        | 0x1f6d0..=0x1f6d2
+        | 0x1f6eb..=0x1f6ec
+        | 0x1f6f4..=0x1f6fc
+        | 0x1f7e0..=0x1f7eb
+        | 0x1f90c..=0x1f93a
+        | 0x1f93c..=0x1f945
+        | 0x1f947..=0x1f9ff
+        | 0x1fa70..=0x1faff
+        | 0x20000..=0x3fffd => 2,
+        _ => 1,
+    }
+}
@@ -0,0 +1,22 @@
+//! Test `NcHeadless` methods.
+
+use serial_test::serial;
+
+use crate::NcHeadless;
+
+#[test]
+#[serial]
+fn ncheadless_render() {
+    let mut term = NcHeadless::new(6, 20).unwrap();
+    assert_eq![6, term.screen().rows()];
+    assert_eq![20, term.screen().cols()];
+
+    term.stdplane().putstr_yx(2, 3, "hello").unwrap();
+    term.render().unwrap();
+    assert_eq![Some((2, 3)), term.screen().find("hello")];
+
+    term.stdplane().putstr_yx(2, 3, "world").unwrap();
+    term.render().unwrap();
+    assert![!term.screen().contains("hello")];
+    assert![term.screen().contains("world")];
+}
@@ -0,0 +1,7 @@
+//! `NcHeadless` & `NcScreen` tests.
+
+#[cfg(test)]
+mod harness;
+
+#[cfg(test)]
+mod screen;
@@ -0,0 +1,101 @@
+//! Test `NcScreen` methods.
+
+use crate::{NcScreen, NcScreenCell, NCSTYLE_BOLD, NCSTYLE_ITALIC, NCSTYLE_UNDERLINE};
+
+#[test]
+fn ncscreen_print_and_move() {
+    let mut screen = NcScreen::new(4, 10);
+    screen.feed(b"\x1b[2;3Hhi\x1b[1;1Hab\r\nc");
+    assert_eq!["ab\nc hi", screen.text().trim_end()];
+    assert_eq![Some((1, 2)), screen.find("hi")];
+    assert_eq![(1, 1), screen.cursor_yx()];
+
+    // horizontal & vertical absolute positioning
+    screen.feed(b"\x1b[4dx\x1b[8Gy");
+    assert_eq![" x     y  ", screen.row_text(3)];
+}
+
+#[test]
+fn ncscreen_wrap_and_scroll() {
+    let mut screen = NcScreen::new(2, 4);
+    screen.feed(b"abcdefgh");
+    assert_eq!["abcd\nefgh", screen.text()];
+    screen.feed(b"i");
+    assert_eq!["efgh\ni", screen.text()];
+}
+
+#[test]
+fn ncscreen_wide_glyphs() {
+    let mut screen = NcScreen::new(2, 3);
+    // a wide glyph that doesn't fit in the rest of the row wraps
+    screen.feed("ab漢".as_bytes());
+    assert_eq!["ab\n漢", screen.text()];
+
+    // and one that doesn't fit in a row is replaced
+    let mut screen = NcScreen::new(2, 1);
+    screen.feed("漢漢".as_bytes());
+    assert_eq!["\u{fffd}\n\u{fffd}", screen.text()];
+}
+
+#[test]
+fn ncscreen_empty() {
+    for &(rows, cols) in &[(0, 4), (4, 0), (0, 0)] {
+        let mut screen = NcScreen::new(rows, cols);
+        screen.feed("ab漢\r\n\x1b[2;2Hc\x1b[Ld\x1b[Me".as_bytes());
+        assert_eq!["", screen.text().trim()];
+    }
+}
+
+#[test]
+fn ncscreen_sgr() {
+    let mut screen = NcScreen::new(1, 10);
+    screen.feed(b"\x1b[1;3m\x1b[38;2;255;128;0m\x1b[48;5;21mA\x1b[0;4mB\x1b[38:2::1:2:3mC\x1b[mD");
+
+    let a = screen.cell(0, 0).unwrap();
+    assert_eq!["A", a.glyph];
+    assert_eq![NCSTYLE_BOLD | NCSTYLE_ITALIC, a.styles];
+    assert_eq![Some(0xff8000), a.fg];
+    assert_eq![Some(0x0000ff), a.bg];
+
+    let b = screen.cell(0, 1).unwrap();
+    assert_eq![NCSTYLE_UNDERLINE, b.styles];
+    assert_eq![None, b.fg];
+    assert_eq![None, b.bg];
+
+    assert_eq![Some(0x010203), screen.cell(0, 2).unwrap().fg];
+    assert_eq![
+        &NcScreenCell {
+            glyph: "D".into(),
+            ..NcScreenCell::new()
+        },
+        screen.cell(0, 3).unwrap()
+    ];
+}
+
+#[test]
+fn ncscreen_erase() {
+    let mut screen = NcScreen::new(2, 5);
+    screen.feed(b"abcde\x1b[2;1Hfghij");
+    screen.feed(b"\x1b[1;3H\x1b[K");
+    assert_eq!["ab\nfghij", screen.text()];
+    screen.feed(b"\x1b[2;2H\x1b[2X");
+    assert_eq!["ab\nf  ij", screen.text()];
+
+    // erased cells take the background color
+    screen.feed(b"\x1b[41m\x1b[2J");
+    assert_eq!["\n", screen.text()];
+    assert_eq![Some(0xcd0000), screen.cell(1, 4).unwrap().bg];
+}
+
+#[test]
+fn ncscreen_utf8_and_split_sequences() {
+    let mut screen = NcScreen::new(1, 8);
+    let bytes = "\x1b[1;2H漢é\x1b]0;title\x07z".as_bytes();
+    // feed it one byte at a time
+    for byte in bytes {
+        screen.feed(&[*byte]);
+    }
+    assert_eq![" 漢éz   ", screen.row_text(0)];
+    assert_eq!["", screen.cell(0, 2).unwrap().glyph];
+    assert_eq![Some((0, 3)), screen.find("é")];
+}
@@ -0,0 +1,73 @@
+//! `NcInput` & `NcEvent` methods and associated functions.
+
+use crate::{
+    nckey_mouse_p, NcError, NcEvent, NcInput, NcKey, NcModifiers, NcResult, NCKEY_BUTTON1,
+    NCKEY_RELEASE, NCKEY_RESIZE,
+};
+
+/// # `NcInput` Methods
+impl NcInput {
+    /// Returns the state of the modifier keys.
+    pub fn modifiers(&self) -> NcModifiers {
+        NcModifiers {
+            alt: self.alt,
+            shift: self.shift,
+            ctrl: self.ctrl,
+        }
+    }
+}
+
+/// # `NcEvent` Constructors
+impl NcEvent {
+    /// Decodes the value returned by [notcurses_getc][crate::notcurses_getc],
+    /// and the [NcInput] it filled.
+    ///
+    /// `u32::MAX` is decoded as [`NcEvent::Eof`], although it's also returned
+    /// when no event was ready. Returns an error if `id` is neither a known
+    /// key nor a valid Unicode scalar value.
+    pub fn from_input(id: u32, input: &NcInput) -> NcResult<NcEvent> {
+        let modifiers = input.modifiers();
+        let event = match id {
+            core::u32::MAX => NcEvent::Eof,
+            NCKEY_RESIZE => NcEvent::Resize,
+            _ if nckey_mouse_p(id) => NcEvent::Mouse {
+                button: if id == NCKEY_RELEASE {
+                    0
+                } else {
+                    (id - NCKEY_BUTTON1 + 1) as u8
+                },
+                y: input.y,
+                x: input.x,
+                release: id == NCKEY_RELEASE,
+                modifiers,
+            },
+            _ => match NcKey::from_u32(id) {
+                Some(key) => NcEvent::Key(key, modifiers),
+                // unknown private use codepoints are passed through as chars
+                None => match core::char::from_u32(id) {
+                    Some(ch) => NcEvent::Char(ch, modifiers),
+                    None => {
+                        return Err(NcError::Code {
+                            func: "notcurses_getc",
+                            code: id as i32,
+                        })
+                    }
+                },
+            },
+        };
+        Ok(event)
+    }
+}
+
+/// # `NcEvent` Methods
+impl NcEvent {
+    /// Returns the modifiers held during the event, if it has any.
+    pub fn modifiers(&self) -> Option<NcModifiers> {
+        match *self {
+            NcEvent::Char(_, modifiers)
+            | NcEvent::Key(_, modifiers)
+            | NcEvent::Mouse { modifiers, .. } => Some(modifiers),
+            NcEvent::Resize | NcEvent::Eof => None,
+        }
+    }
+}
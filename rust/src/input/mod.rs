@@ -0,0 +1,106 @@
+//! `NcInput`
+
+// functions manually reimplemented: 1
+// ------------------------------------------
+// (+) done: 1 / 0
+// (#) test: 0 / 1
+// ------------------------------------------
+// + ncinput_equal_p
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+#[cfg(feature = "async")]
+mod stream;
+#[cfg(feature = "async")]
+pub use stream::*;
+
+/// Reads and decodes input events
+///
+/// Reads from stdin and decodes the input to stdout,
+/// including synthesized events and mouse events.
+///
+/// To exit, generate EOF (usually Ctrl+‘d’).
+pub type NcInput = crate::bindings::ffi::ncinput;
+
+/// Compares two ncinput structs for data equality by doing a field-by-field
+/// comparison for equality (excepting seqnum).
+///
+/// Returns true if the two are data-equivalent.
+pub fn ncinput_equal_p(n1: NcInput, n2: NcInput) -> bool {
+    if n1.id != n2.id {
+        return false;
+    }
+    if n1.y != n2.y || n1.x != n2.x {
+        return false;
+    }
+    // do not check seqnum
+    true
+}
+
+/// New `NcInput`.
+impl NcInput {
+    pub fn new() -> NcInput {
+        NcInput {
+            id: 0,
+            y: 0,
+            x: 0,
+            alt: false,
+            shift: false,
+            ctrl: false,
+            seqnum: 0,
+        }
+    }
+}
+
+/// The state of the modifier keys during an [`NcEvent`].
+///
+/// Taken from the `alt`, `shift` & `ctrl` fields of [`NcInput`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NcModifiers {
+    /// Was Alt held?
+    pub alt: bool,
+    /// Was Shift held?
+    pub shift: bool,
+    /// Was Ctrl held?
+    pub ctrl: bool,
+}
+
+/// A decoded input event, as returned by the [`Notcurses`][crate::Notcurses]
+/// input methods, e.g. [`get_event_blocking`][crate::Notcurses#method.get_event_blocking].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcEvent {
+    /// A Unicode character.
+    Char(char, NcModifiers),
+
+    /// A synthesized key, other than the mouse events and
+    /// [`NcKey::Resize`][crate::NcKey::Resize].
+    Key(crate::NcKey, NcModifiers),
+
+    /// A synthesized mouse event.
+    Mouse {
+        /// The button number, from 1 to 11, or 0 if unknown (on release).
+        button: u8,
+        /// The row where the event happened.
+        y: i32,
+        /// The column where the event happened.
+        x: i32,
+        /// Whether it's a button release ([`NCKEY_RELEASE`][crate::NCKEY_RELEASE]).
+        release: bool,
+        /// The modifiers held.
+        modifiers: NcModifiers,
+    },
+
+    /// The terminal was resized.
+    Resize,
+
+    /// The input reached EOF, there was an error reading it, or the wait
+    /// was interrupted by a signal.
+    ///
+    /// Notcurses returns the same `(char32_t)-1` value in all these cases,
+    /// and also when no event was ready, which the methods with a timeout
+    /// return as `None` instead.
+    Eof,
+}
@@ -0,0 +1,242 @@
+//! `NcEventStream`, an async `Stream` of input events.
+//!
+//! Available with the `async` feature. The `async-tokio` and `async-smol`
+//! features add the [`NcReadiness`] adapters for those executors.
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::{io, os::unix::io::RawFd};
+
+use futures_core::Stream;
+
+use crate::{
+    ncdirect_getc_nblock, ncdirect_inputready_fd, notcurses_getc_nblock, notcurses_inputready_fd,
+    NcDirect, NcEvent, NcInput, Notcurses,
+};
+
+/// A context whose input events can be awaited through a file descriptor.
+///
+/// Implemented by [`Notcurses`] and [`NcDirect`].
+pub trait NcInputSource {
+    /// Returns the file descriptor that becomes readable when input is ready.
+    fn inputready_fd(&mut self) -> RawFd;
+
+    /// Reads an input event without blocking.
+    ///
+    /// Returns the same raw value as [`notcurses_getc_nblock`].
+    fn getc_nblock(&mut self, input: &mut NcInput) -> u32;
+}
+
+impl NcInputSource for Notcurses {
+    fn inputready_fd(&mut self) -> RawFd {
+        unsafe { notcurses_inputready_fd(self) }
+    }
+
+    fn getc_nblock(&mut self, input: &mut NcInput) -> u32 {
+        notcurses_getc_nblock(self, input)
+    }
+}
+
+impl NcInputSource for NcDirect {
+    fn inputready_fd(&mut self) -> RawFd {
+        unsafe { ncdirect_inputready_fd(self) }
+    }
+
+    fn getc_nblock(&mut self, input: &mut NcInput) -> u32 {
+        ncdirect_getc_nblock(self, input)
+    }
+}
+
+/// A hook into an async executor, that wakes the task when a file
+/// descriptor becomes readable.
+///
+/// Implement it to use [`NcEventStream`] with any executor, or enable the
+/// `async-tokio` or `async-smol` features for the bundled adapters.
+pub trait NcReadiness {
+    /// Returns `Ready` once the file descriptor became readable since the
+    /// last time it returned `Ready`, otherwise arranges for the task in `cx`
+    /// to be woken up when it does.
+    fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+}
+
+/// An async [`Stream`] of the input events of a [`Notcurses`]
+/// or [`NcDirect`] context.
+///
+/// It drains the input with the non-blocking getc until no more events are
+/// ready, and then waits on the input-ready file descriptor through an
+/// [`NcReadiness`] hook.
+///
+/// Note that notcurses returns the same value when there's no input ready,
+/// on errors and on EOF, so the stream never ends by itself.
+#[derive(Debug)]
+pub struct NcEventStream<'a, S: ?Sized, R> {
+    source: &'a mut S,
+    readiness: R,
+    // no more events were ready last time we tried
+    drained: bool,
+}
+
+/// # `NcEventStream` Constructors
+impl<'a, S: NcInputSource + ?Sized, R: NcReadiness> NcEventStream<'a, S, R> {
+    /// New NcEventStream, expects the context, and the readiness hook
+    /// for its input-ready file descriptor.
+    pub fn new(source: &'a mut S, readiness: R) -> Self {
+        Self {
+            source,
+            readiness,
+            drained: false,
+        }
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+impl<'a, S: NcInputSource + ?Sized> NcEventStream<'a, S, NcTokioReadiness> {
+    /// New NcEventStream, using the tokio reactor.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn tokio(source: &'a mut S) -> io::Result<Self> {
+        let readiness = NcTokioReadiness::new(source.inputready_fd())?;
+        Ok(Self::new(source, readiness))
+    }
+}
+
+#[cfg(feature = "async-smol")]
+impl<'a, S: NcInputSource + ?Sized> NcEventStream<'a, S, NcSmolReadiness> {
+    /// New NcEventStream, using the async-io reactor (smol & async-std).
+    pub fn smol(source: &'a mut S) -> io::Result<Self> {
+        let readiness = NcSmolReadiness::new(source.inputready_fd())?;
+        Ok(Self::new(source, readiness))
+    }
+}
+
+/// # `NcEventStream` Methods
+impl<'a, S: ?Sized, R> NcEventStream<'a, S, R> {
+    /// Returns a reference to the context.
+    pub fn source(&mut self) -> &mut S {
+        self.source
+    }
+
+    /// Consumes the stream, returning the readiness hook.
+    pub fn into_readiness(self) -> R {
+        self.readiness
+    }
+}
+
+impl<'a, S: NcInputSource + ?Sized, R: NcReadiness + Unpin> Stream for NcEventStream<'a, S, R> {
+    type Item = io::Result<NcEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if !this.drained {
+                let mut input = NcInput::new();
+                let id = this.source.getc_nblock(&mut input);
+                match NcEvent::from_input(id, &input) {
+                    Ok(NcEvent::Eof) => this.drained = true,
+                    Ok(event) => return Poll::Ready(Some(Ok(event))),
+                    Err(err) => return Poll::Ready(Some(Err(err.into()))),
+                }
+            }
+            match this.readiness.poll_readable(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(Ok(())) => this.drained = false,
+            }
+        }
+    }
+}
+
+/// An [`NcReadiness`] adapter for the tokio reactor.
+///
+/// Available with the `async-tokio` feature.
+#[cfg(feature = "async-tokio")]
+#[derive(Debug)]
+pub struct NcTokioReadiness {
+    fd: tokio::io::unix::AsyncFd<RawFd>,
+}
+
+#[cfg(feature = "async-tokio")]
+impl NcTokioReadiness {
+    /// New NcTokioReadiness, registers the file descriptor in the reactor.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn new(fd: RawFd) -> io::Result<Self> {
+        Ok(Self {
+            fd: tokio::io::unix::AsyncFd::new(fd)?,
+        })
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+impl NcReadiness for NcTokioReadiness {
+    fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.fd.poll_read_ready(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+            Poll::Ready(Ok(mut guard)) => {
+                // the stream drains all the input after this, so any event
+                // arriving later must be reported again
+                guard.clear_ready();
+                Poll::Ready(Ok(()))
+            }
+        }
+    }
+}
+
+/// An [`NcReadiness`] adapter for the async-io reactor, used by smol
+/// and async-std.
+///
+/// Available with the `async-smol` feature.
+#[cfg(feature = "async-smol")]
+#[derive(Debug)]
+pub struct NcSmolReadiness {
+    fd: async_io::Async<BorrowedFd>,
+    // the file status flags before the reactor made the fd non-blocking
+    flags: i32,
+}
+
+// A file descriptor that is not closed on drop, since it's owned by notcurses.
+#[cfg(feature = "async-smol")]
+#[derive(Debug)]
+struct BorrowedFd(RawFd);
+
+#[cfg(feature = "async-smol")]
+impl std::os::unix::io::AsRawFd for BorrowedFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+#[cfg(feature = "async-smol")]
+impl NcSmolReadiness {
+    /// New NcSmolReadiness, registers the file descriptor in the reactor.
+    ///
+    /// The reactor makes the file descriptor non-blocking, so its previous
+    /// flags are restored when this is dropped.
+    pub fn new(fd: RawFd) -> io::Result<Self> {
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        if flags < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            fd: async_io::Async::new(BorrowedFd(fd))?,
+            flags,
+        })
+    }
+}
+
+#[cfg(feature = "async-smol")]
+impl Drop for NcSmolReadiness {
+    fn drop(&mut self) {
+        unsafe { libc::fcntl(self.fd.get_ref().0, libc::F_SETFL, self.flags) };
+    }
+}
+
+#[cfg(feature = "async-smol")]
+impl NcReadiness for NcSmolReadiness {
+    fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.fd.poll_readable(cx)
+    }
+}
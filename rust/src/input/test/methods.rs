@@ -0,0 +1,57 @@
+//! Test `NcInput` & `NcEvent` methods.
+
+use crate::{
+    NcEvent, NcInput, NcKey, NcModifiers, NCKEY_BUTTON3, NCKEY_F05, NCKEY_RELEASE, NCKEY_RESIZE,
+};
+
+#[test]
+fn ncevent_from_input() {
+    let mut input = NcInput::new();
+    assert_eq![NcEvent::Eof, NcEvent::from_input(u32::MAX, &input).unwrap()];
+    assert![NcEvent::from_input(0xd800, &input).is_err()];
+    assert_eq![
+        NcEvent::Resize,
+        NcEvent::from_input(NCKEY_RESIZE, &input).unwrap()
+    ];
+
+    input.ctrl = true;
+    let ctrl = NcModifiers {
+        ctrl: true,
+        ..Default::default()
+    };
+    assert_eq![
+        NcEvent::Char('x', ctrl),
+        NcEvent::from_input('x' as u32, &input).unwrap()
+    ];
+    assert_eq![
+        NcEvent::Key(NcKey::F05, ctrl),
+        NcEvent::from_input(NCKEY_F05, &input).unwrap()
+    ];
+}
+
+#[test]
+fn ncevent_from_input_mouse() {
+    let mut input = NcInput::new();
+    input.y = 4;
+    input.x = 7;
+    assert_eq![
+        NcEvent::Mouse {
+            button: 3,
+            y: 4,
+            x: 7,
+            release: false,
+            modifiers: NcModifiers::default(),
+        },
+        NcEvent::from_input(NCKEY_BUTTON3, &input).unwrap()
+    ];
+    assert_eq![
+        NcEvent::Mouse {
+            button: 0,
+            y: 4,
+            x: 7,
+            release: true,
+            modifiers: NcModifiers::default(),
+        },
+        NcEvent::from_input(NCKEY_RELEASE, &input).unwrap()
+    ];
+}
@@ -0,0 +1,7 @@
+//! `NcInput` tests.
+
+#[cfg(test)]
+mod methods;
+
+#[cfg(all(test, feature = "async"))]
+mod stream;
@@ -0,0 +1,80 @@
+//! Test `NcEventStream`.
+
+use core::{
+    pin::Pin,
+    ptr::null,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+use std::{cell::RefCell, collections::VecDeque, io, os::unix::io::RawFd, rc::Rc};
+
+use futures_core::Stream;
+
+use crate::{NcEvent, NcEventStream, NcInput, NcInputSource, NcModifiers, NcReadiness};
+
+// A source that returns the queued ids, and u32::MAX when empty, like notcurses.
+struct Queue(VecDeque<u32>);
+
+impl NcInputSource for Queue {
+    fn inputready_fd(&mut self) -> RawFd {
+        -1
+    }
+
+    fn getc_nblock(&mut self, input: &mut NcInput) -> u32 {
+        *input = NcInput::new();
+        self.0.pop_front().unwrap_or(u32::MAX)
+    }
+}
+
+// Readiness that is reported once per queued value.
+struct Ready(Rc<RefCell<VecDeque<bool>>>);
+
+impl NcReadiness for Ready {
+    fn poll_readable(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.0.borrow_mut().pop_front() {
+            Some(true) => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    unsafe { Waker::from_raw(RawWaker::new(null(), &VTABLE)) }
+}
+
+fn next<R: NcReadiness + Unpin>(stream: &mut NcEventStream<'_, Queue, R>) -> Poll<Option<NcEvent>> {
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    Pin::new(stream)
+        .poll_next(&mut cx)
+        .map(|event| event.map(Result::unwrap))
+}
+
+fn char_event(ch: char) -> Poll<Option<NcEvent>> {
+    Poll::Ready(Some(NcEvent::Char(ch, NcModifiers::default())))
+}
+
+#[test]
+fn nceventstream_drains_then_waits() {
+    let mut queue = Queue(vec!['a' as u32, 'b' as u32].into());
+    let ready = Rc::new(RefCell::new(vec![false, true].into()));
+    let mut stream = NcEventStream::new(&mut queue, Ready(ready.clone()));
+
+    // what's already queued is returned without waiting
+    assert_eq![char_event('a'), next(&mut stream)];
+    assert_eq![char_event('b'), next(&mut stream)];
+    // drained, and the descriptor isn't readable
+    assert_eq![Poll::Pending, next(&mut stream)];
+    // readable, but no events came
+    assert_eq![Poll::Pending, next(&mut stream)];
+
+    // new input isn't read until the descriptor is readable again
+    stream.source().0.push_back('c' as u32);
+    assert_eq![Poll::Pending, next(&mut stream)];
+    ready.borrow_mut().push_back(true);
+    assert_eq![char_event('c'), next(&mut stream)];
+}
@@ -0,0 +1,80 @@
+//! `NcKey` methods and associated functions.
+
+use core::{fmt, str::FromStr};
+
+use crate::{nckey_mouse_p, NcKey, NcParseError, NCKEY_F00, NCKEY_F60};
+
+/// # `NcKey` Methods
+impl NcKey {
+    /// Is this a synthesized mouse event (a button press or release)?
+    pub fn is_mouse(&self) -> bool {
+        nckey_mouse_p(*self as u32)
+    }
+
+    /// Returns the number of the function key (0 to 60), if it's one.
+    pub fn function_number(&self) -> Option<u32> {
+        let code = *self as u32;
+        if (NCKEY_F00..=NCKEY_F60).contains(&code) {
+            Some(code - NCKEY_F00)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<NcKey> for u32 {
+    fn from(key: NcKey) -> u32 {
+        key as u32
+    }
+}
+
+impl fmt::Display for NcKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the name of the key, ignoring ASCII case.
+///
+/// Besides the names returned by [`name`][NcKey#method.name], it accepts
+/// the function keys with a leading zero (`"F05"`), and the aliases
+/// `"Return"`, `"Insert"`, `"Delete"`, `"PageUp"`, `"PageDown"`,
+/// `"ScrollUp"` & `"ScrollDown"`.
+impl FromStr for NcKey {
+    type Err = NcParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let alias = match s.to_ascii_lowercase().as_str() {
+            "return" => Some(NcKey::Enter),
+            "insert" => Some(NcKey::Ins),
+            "delete" => Some(NcKey::Del),
+            "pageup" => Some(NcKey::PgUp),
+            "pagedown" => Some(NcKey::PgDown),
+            "scrollup" => Some(NcKey::Button4),
+            "scrolldown" => Some(NcKey::Button5),
+            _ => None,
+        };
+        if let Some(key) = alias {
+            return Ok(key);
+        }
+
+        // function keys, with or without leading zeros
+        if let Some(num) = s.strip_prefix(|c| c == 'F' || c == 'f') {
+            if !num.is_empty() && num.len() <= 2 && num.bytes().all(|b| b.is_ascii_digit()) {
+                if let Some(key) = num
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|n| NcKey::from_u32(NCKEY_F00 + n))
+                {
+                    return Ok(key);
+                }
+            }
+        }
+
+        NcKey::ALL
+            .iter()
+            .find(|key| key.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| NcParseError::new("NcKey", s))
+    }
+}
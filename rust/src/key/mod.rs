@@ -0,0 +1,185 @@
+//! `NcKey`
+
+// functions manually reimplemented: 2
+// ------------------------------------------
+// (+) done: 2 / 0
+// (#) test: 0 / 2
+// ------------------------------------------
+// + nckey_mouse_p
+// + nckey_supppuab_p
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+use crate::keycodes::*;
+
+/// Defines [`NcKey`] from a list of `Variant = NCKEY_CONSTANT, "Name";`,
+/// along with the lookup tables used by its conversion methods.
+macro_rules! nckeys {
+    ($( $(#[$meta:meta])* $variant:ident = $code:ident, $name:literal; )*) => {
+        /// A synthesized key, one of the `NCKEY_*` constants.
+        ///
+        /// It can be converted from and into its raw `u32` value, and from
+        /// and into its name (e.g. `"F5"`, `"PgDown"`, `"Button1"`).
+        #[repr(u32)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum NcKey {
+            $( $(#[$meta])* $variant = $code, )*
+        }
+
+        impl NcKey {
+            /// All the keys, in ascending order of their `u32` value.
+            pub const ALL: &'static [NcKey] = &[ $( NcKey::$variant, )* ];
+
+            /// Returns the key with this `u32` value, if there's any.
+            pub fn from_u32(code: u32) -> Option<NcKey> {
+                match code {
+                    $( $code => Some(NcKey::$variant), )*
+                    _ => None,
+                }
+            }
+
+            /// Returns the name of the key, as used by its `Display` and
+            /// `FromStr` implementations.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $( NcKey::$variant => $name, )*
+                }
+            }
+        }
+    };
+}
+
+nckeys! {
+    Invalid = NCKEY_INVALID, "Invalid";
+    /// Generated internally in response to `SIGWINCH`.
+    Resize = NCKEY_RESIZE, "Resize";
+    Up = NCKEY_UP, "Up";
+    Right = NCKEY_RIGHT, "Right";
+    Down = NCKEY_DOWN, "Down";
+    Left = NCKEY_LEFT, "Left";
+    Ins = NCKEY_INS, "Ins";
+    Del = NCKEY_DEL, "Del";
+    /// Backspace (sometimes).
+    Backspace = NCKEY_BACKSPACE, "Backspace";
+    PgDown = NCKEY_PGDOWN, "PgDown";
+    PgUp = NCKEY_PGUP, "PgUp";
+    Home = NCKEY_HOME, "Home";
+    End = NCKEY_END, "End";
+    F00 = NCKEY_F00, "F0";
+    F01 = NCKEY_F01, "F1";
+    F02 = NCKEY_F02, "F2";
+    F03 = NCKEY_F03, "F3";
+    F04 = NCKEY_F04, "F4";
+    F05 = NCKEY_F05, "F5";
+    F06 = NCKEY_F06, "F6";
+    F07 = NCKEY_F07, "F7";
+    F08 = NCKEY_F08, "F8";
+    F09 = NCKEY_F09, "F9";
+    F10 = NCKEY_F10, "F10";
+    F11 = NCKEY_F11, "F11";
+    F12 = NCKEY_F12, "F12";
+    F13 = NCKEY_F13, "F13";
+    F14 = NCKEY_F14, "F14";
+    F15 = NCKEY_F15, "F15";
+    F16 = NCKEY_F16, "F16";
+    F17 = NCKEY_F17, "F17";
+    F18 = NCKEY_F18, "F18";
+    F19 = NCKEY_F19, "F19";
+    F20 = NCKEY_F20, "F20";
+    F21 = NCKEY_F21, "F21";
+    F22 = NCKEY_F22, "F22";
+    F23 = NCKEY_F23, "F23";
+    F24 = NCKEY_F24, "F24";
+    F25 = NCKEY_F25, "F25";
+    F26 = NCKEY_F26, "F26";
+    F27 = NCKEY_F27, "F27";
+    F28 = NCKEY_F28, "F28";
+    F29 = NCKEY_F29, "F29";
+    F30 = NCKEY_F30, "F30";
+    F31 = NCKEY_F31, "F31";
+    F32 = NCKEY_F32, "F32";
+    F33 = NCKEY_F33, "F33";
+    F34 = NCKEY_F34, "F34";
+    F35 = NCKEY_F35, "F35";
+    F36 = NCKEY_F36, "F36";
+    F37 = NCKEY_F37, "F37";
+    F38 = NCKEY_F38, "F38";
+    F39 = NCKEY_F39, "F39";
+    F40 = NCKEY_F40, "F40";
+    F41 = NCKEY_F41, "F41";
+    F42 = NCKEY_F42, "F42";
+    F43 = NCKEY_F43, "F43";
+    F44 = NCKEY_F44, "F44";
+    F45 = NCKEY_F45, "F45";
+    F46 = NCKEY_F46, "F46";
+    F47 = NCKEY_F47, "F47";
+    F48 = NCKEY_F48, "F48";
+    F49 = NCKEY_F49, "F49";
+    F50 = NCKEY_F50, "F50";
+    F51 = NCKEY_F51, "F51";
+    F52 = NCKEY_F52, "F52";
+    F53 = NCKEY_F53, "F53";
+    F54 = NCKEY_F54, "F54";
+    F55 = NCKEY_F55, "F55";
+    F56 = NCKEY_F56, "F56";
+    F57 = NCKEY_F57, "F57";
+    F58 = NCKEY_F58, "F58";
+    F59 = NCKEY_F59, "F59";
+    F60 = NCKEY_F60, "F60";
+    /// Enter, also known as Return ([`NCKEY_RETURN`]).
+    Enter = NCKEY_ENTER, "Enter";
+    /// Clear screen (keypad 5 without numlock).
+    Cls = NCKEY_CLS, "Cls";
+    /// Down+left (keypad 1 without numlock).
+    DLeft = NCKEY_DLEFT, "DLeft";
+    /// Down+right (keypad 3 without numlock).
+    DRight = NCKEY_DRIGHT, "DRight";
+    /// Up+left (keypad 7 without numlock).
+    ULeft = NCKEY_ULEFT, "ULeft";
+    /// Up+right (keypad 9 without numlock).
+    URight = NCKEY_URIGHT, "URight";
+    /// The middle of the keypad.
+    Center = NCKEY_CENTER, "Center";
+    Begin = NCKEY_BEGIN, "Begin";
+    Cancel = NCKEY_CANCEL, "Cancel";
+    Close = NCKEY_CLOSE, "Close";
+    Command = NCKEY_COMMAND, "Command";
+    Copy = NCKEY_COPY, "Copy";
+    Exit = NCKEY_EXIT, "Exit";
+    Print = NCKEY_PRINT, "Print";
+    Refresh = NCKEY_REFRESH, "Refresh";
+    Button1 = NCKEY_BUTTON1, "Button1";
+    Button2 = NCKEY_BUTTON2, "Button2";
+    Button3 = NCKEY_BUTTON3, "Button3";
+    /// Also scroll up ([`NCKEY_SCROLL_UP`]).
+    Button4 = NCKEY_BUTTON4, "Button4";
+    /// Also scroll down ([`NCKEY_SCROLL_DOWN`]).
+    Button5 = NCKEY_BUTTON5, "Button5";
+    Button6 = NCKEY_BUTTON6, "Button6";
+    Button7 = NCKEY_BUTTON7, "Button7";
+    Button8 = NCKEY_BUTTON8, "Button8";
+    Button9 = NCKEY_BUTTON9, "Button9";
+    Button10 = NCKEY_BUTTON10, "Button10";
+    Button11 = NCKEY_BUTTON11, "Button11";
+    /// A mouse button release.
+    Release = NCKEY_RELEASE, "Release";
+}
+
+/// Is this u32 a Supplementary Private Use Area-B codepoint?
+///
+/// Links:
+/// - https://en.wikipedia.org/wiki/Private_Use_Areas
+/// - https://codepoints.net/supplementary_private_use_area-b
+#[inline]
+pub fn nckey_supppuab_p(w: u32) -> bool {
+    w >= 0x100000 && w <= 0x10fffd
+}
+
+/// Is the event a synthesized mouse event?
+#[inline]
+pub fn nckey_mouse_p(r: u32) -> bool {
+    r >= NCKEY_BUTTON1 && r <= NCKEY_RELEASE
+}
@@ -0,0 +1,56 @@
+//! Test `NcKey` methods and associated functions.
+
+use crate::{NcKey, NCKEY_BUTTON4, NCKEY_ENTER, NCKEY_F05, NCKEY_PGDOWN, NCKEY_RESIZE};
+
+#[test]
+fn nckey_u32_roundtrip() {
+    for key in NcKey::ALL {
+        assert_eq![Some(*key), NcKey::from_u32(u32::from(*key))];
+    }
+    assert_eq![Some(NcKey::F05), NcKey::from_u32(NCKEY_F05)];
+    assert_eq![Some(NcKey::Resize), NcKey::from_u32(NCKEY_RESIZE)];
+    assert_eq![NCKEY_PGDOWN, NcKey::PgDown.into()];
+    assert_eq![None, NcKey::from_u32('a' as u32)];
+}
+
+#[test]
+fn nckey_name_roundtrip() {
+    for key in NcKey::ALL {
+        assert_eq![Ok(*key), key.to_string().parse()];
+    }
+    assert_eq!["F5", NcKey::F05.to_string()];
+    assert_eq!["PgDown", NcKey::PgDown.to_string()];
+    assert_eq!["Button1", NcKey::Button1.to_string()];
+}
+
+#[test]
+fn nckey_from_str() {
+    assert_eq![Ok(NcKey::F05), "F05".parse()];
+    assert_eq![Ok(NcKey::F05), "f5".parse()];
+    assert_eq![Ok(NcKey::F60), "F60".parse()];
+    assert_eq![Ok(NcKey::PgDown), "pgdown".parse()];
+    assert_eq![Ok(NcKey::PgDown), "PageDown".parse()];
+    assert_eq![
+        Some(NCKEY_ENTER),
+        "Return".parse::<NcKey>().ok().map(u32::from)
+    ];
+    assert_eq![
+        Some(NCKEY_BUTTON4),
+        "ScrollUp".parse::<NcKey>().ok().map(u32::from)
+    ];
+
+    assert!["F61".parse::<NcKey>().is_err()];
+    assert!["F".parse::<NcKey>().is_err()];
+    let err = "Foo".parse::<NcKey>().unwrap_err();
+    assert_eq!["NcKey", err.kind()];
+    assert_eq!["Foo", err.input()];
+}
+
+#[test]
+fn nckey_function_number() {
+    assert_eq![Some(0), NcKey::F00.function_number()];
+    assert_eq![Some(60), NcKey::F60.function_number()];
+    assert_eq![None, NcKey::Up.function_number()];
+    assert![NcKey::Release.is_mouse()];
+    assert![!NcKey::Enter.is_mouse()];
+}
@@ -0,0 +1,4 @@
+//! `NcKey` tests.
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,306 @@
+//! `NcChord`, `NcKeySequence` & `NcKeymap` methods and associated functions.
+
+use core::{fmt, str::FromStr};
+
+use crate::{
+    nckey_mouse_p, NcChord, NcChordKey, NcInput, NcKey, NcKeySequence, NcKeymap, NcKeymapMatch,
+    NcModifiers, NcParseError,
+};
+
+/// # `NcChord` Constructors
+impl NcChord {
+    /// New NcChord, expects the key and the modifiers.
+    pub fn new(key: NcChordKey, modifiers: NcModifiers) -> Self {
+        Self { key, modifiers }.normalized()
+    }
+
+    /// Returns the chord pressed in the [`NcInput`], if it's a key press.
+    pub fn from_input(input: &NcInput) -> Option<Self> {
+        let key = match input.id {
+            0 | core::u32::MAX => return None,
+            id if id == NcKey::Resize as u32 || nckey_mouse_p(id) => return None,
+            id => match NcKey::from_u32(id) {
+                Some(key) => NcChordKey::Key(key),
+                None => NcChordKey::Char(core::char::from_u32(id)?),
+            },
+        };
+        Some(Self::new(key, input.modifiers()))
+    }
+}
+
+/// # `NcChord` Methods
+impl NcChord {
+    /// Does the [`NcInput`] match this chord, including its modifiers?
+    ///
+    /// Unlike [`ncinput_equal_p`][crate::ncinput_equal_p], which ignores them.
+    pub fn matches(&self, input: &NcInput) -> bool {
+        NcChord::from_input(input) == Some(*self)
+    }
+
+    /// Returns an [`NcInput`] with this chord, e.g. to be used as the
+    /// shortcut of an [`NcMenuItem`][crate::NcMenuItem].
+    pub fn to_input(&self) -> NcInput {
+        let mut input = NcInput::new();
+        input.id = match self.key {
+            NcChordKey::Char(ch) => ch as u32,
+            NcChordKey::Key(key) => key as u32,
+        };
+        input.alt = self.modifiers.alt;
+        input.shift = self.modifiers.shift;
+        input.ctrl = self.modifiers.ctrl;
+        input
+    }
+
+    // private methods --
+
+    // Ctrl+letter is reported with the uppercase letter, regardless of shift.
+    fn normalized(mut self) -> Self {
+        if let NcChordKey::Char(ch) = self.key {
+            if self.modifiers.ctrl && ch.is_ascii_alphabetic() {
+                self.key = NcChordKey::Char(ch.to_ascii_uppercase());
+            }
+        }
+        self
+    }
+}
+
+impl From<NcChord> for NcInput {
+    fn from(chord: NcChord) -> NcInput {
+        chord.to_input()
+    }
+}
+
+impl fmt::Display for NcChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.ctrl {
+            f.write_str("C-")?;
+        }
+        if self.modifiers.alt {
+            f.write_str("M-")?;
+        }
+        if self.modifiers.shift {
+            f.write_str("S-")?;
+        }
+        match self.key {
+            NcChordKey::Char(' ') => f.write_str("Space"),
+            NcChordKey::Char('\t') => f.write_str("Tab"),
+            NcChordKey::Char('\x1b') => f.write_str("Esc"),
+            NcChordKey::Char(ch) if self.modifiers.ctrl && ch.is_ascii_uppercase() => {
+                write!(f, "{}", ch.to_ascii_lowercase())
+            }
+            NcChordKey::Char(ch) => write!(f, "{}", ch),
+            NcChordKey::Key(key) => write!(f, "{}", key),
+        }
+    }
+}
+
+impl FromStr for NcChord {
+    type Err = NcParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || NcParseError::new("NcChord", s);
+
+        let mut modifiers = NcModifiers::default();
+        let mut rest = s;
+        // a prefix is only a modifier if there's a key left after it,
+        // so that e.g. "C--" is ctrl + '-'
+        while rest.len() > 2 && rest.as_bytes()[1] == b'-' {
+            match rest.as_bytes()[0] {
+                b'C' => modifiers.ctrl = true,
+                b'M' | b'A' => modifiers.alt = true,
+                b'S' => modifiers.shift = true,
+                _ => break,
+            }
+            rest = &rest[2..];
+        }
+
+        let mut chars = rest.chars();
+        let key = match (chars.next(), chars.next()) {
+            (None, _) => return Err(err()),
+            (Some(ch), None) => NcChordKey::Char(ch),
+            _ => match rest.to_ascii_lowercase().as_str() {
+                "space" => NcChordKey::Char(' '),
+                "tab" => NcChordKey::Char('\t'),
+                "esc" | "escape" => NcChordKey::Char('\x1b'),
+                _ => {
+                    let key = rest.parse::<NcKey>().map_err(|_| err())?;
+                    if key == NcKey::Resize || key == NcKey::Invalid || key.is_mouse() {
+                        return Err(err());
+                    }
+                    NcChordKey::Key(key)
+                }
+            },
+        };
+        Ok(NcChord::new(key, modifiers))
+    }
+}
+
+/// # `NcKeySequence` Constructors
+impl NcKeySequence {
+    /// New NcKeySequence, expects at least one chord.
+    pub fn new(chords: Vec<NcChord>) -> Option<Self> {
+        if chords.is_empty() {
+            None
+        } else {
+            Some(Self(chords))
+        }
+    }
+}
+
+/// # `NcKeySequence` Methods
+impl NcKeySequence {
+    /// Returns the chords of the sequence.
+    pub fn chords(&self) -> &[NcChord] {
+        &self.0
+    }
+}
+
+impl From<NcChord> for NcKeySequence {
+    fn from(chord: NcChord) -> NcKeySequence {
+        Self(vec![chord])
+    }
+}
+
+impl fmt::Display for NcKeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, chord) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", chord)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for NcKeySequence {
+    type Err = NcParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chords = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<NcChord>, _>>()?;
+        NcKeySequence::new(chords).ok_or_else(|| NcParseError::new("NcKeySequence", s))
+    }
+}
+
+impl<A> Default for NcKeymap<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # `NcKeymap` Constructors
+impl<A> NcKeymap<A> {
+    /// New empty NcKeymap.
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+/// # `NcKeymap` Methods
+impl<A> NcKeymap<A> {
+    /// Binds the sequence parsed from `sequence` to the `action`,
+    /// replacing any previous binding for the same sequence.
+    pub fn bind(&mut self, sequence: &str, action: A) -> Result<(), NcParseError> {
+        self.bind_sequence(sequence.parse()?, action);
+        Ok(())
+    }
+
+    /// Binds the `sequence` to the `action`, replacing any previous binding
+    /// for the same sequence.
+    pub fn bind_sequence(&mut self, sequence: NcKeySequence, action: A) {
+        match self.bindings.iter_mut().find(|(seq, _)| *seq == sequence) {
+            Some(binding) => binding.1 = action,
+            None => self.bindings.push((sequence, action)),
+        }
+    }
+
+    /// Removes the binding for the `sequence`, returning its action.
+    pub fn unbind(&mut self, sequence: &NcKeySequence) -> Option<A> {
+        let index = self.bindings.iter().position(|(seq, _)| seq == sequence)?;
+        Some(self.bindings.remove(index).1)
+    }
+
+    /// Returns the action bound to the `sequence`.
+    pub fn get(&self, sequence: &NcKeySequence) -> Option<&A> {
+        self.bindings
+            .iter()
+            .find(|(seq, _)| seq == sequence)
+            .map(|(_, action)| action)
+    }
+
+    /// Returns an iterator over the bindings.
+    pub fn iter(&self) -> impl Iterator<Item = (&NcKeySequence, &A)> {
+        self.bindings.iter().map(|(seq, action)| (seq, action))
+    }
+
+    /// Returns the chords of a partially typed sequence.
+    pub fn pending(&self) -> &[NcChord] {
+        &self.pending
+    }
+
+    /// Discards the chords of a partially typed sequence.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Feeds an input event, as filled by [`notcurses_getc`][crate::notcurses_getc].
+    ///
+    /// A sequence that is bound is matched as soon as it's completed, even
+    /// if it's also the start of a longer one. A chord that breaks a partially
+    /// typed sequence is matched again on its own.
+    pub fn feed(&mut self, input: &NcInput) -> NcKeymapMatch<'_, A> {
+        let chord = match NcChord::from_input(input) {
+            Some(chord) => chord,
+            None => return NcKeymapMatch::Unbound,
+        };
+        if !self.continues_pending(chord) {
+            self.pending.clear();
+        }
+        self.pending.push(chord);
+
+        let pending = &self.pending;
+        if let Some(index) = self.bindings.iter().position(|(seq, _)| seq.0 == *pending) {
+            self.pending.clear();
+            return NcKeymapMatch::Matched(&mut self.bindings[index].1);
+        }
+        if self
+            .bindings
+            .iter()
+            .any(|(seq, _)| seq.0.starts_with(pending))
+        {
+            return NcKeymapMatch::Pending;
+        }
+        self.pending.clear();
+        NcKeymapMatch::Unbound
+    }
+
+    // Returns true if some binding starts with the pending chords
+    // followed by `chord`.
+    fn continues_pending(&self, chord: NcChord) -> bool {
+        let len = self.pending.len();
+        self.bindings.iter().any(|(seq, _)| {
+            seq.0.len() > len && seq.0.starts_with(&self.pending) && seq.0[len] == chord
+        })
+    }
+}
+
+impl<A: FnMut()> NcKeymap<A> {
+    /// Feeds an input event, and calls the action of the completed sequence.
+    ///
+    /// Returns true if an action was called.
+    pub fn dispatch(&mut self, input: &NcInput) -> bool {
+        match self.feed(input) {
+            NcKeymapMatch::Matched(action) => {
+                action();
+                true
+            }
+            NcKeymapMatch::Pending | NcKeymapMatch::Unbound => false,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! `NcKeymap`, `NcKeySequence` & `NcChord`, for binding actions to shortcuts.
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+use crate::{NcKey, NcModifiers};
+
+/// The key part of an [`NcChord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NcChordKey {
+    /// A Unicode character.
+    ///
+    /// Ctrl+letter chords are always stored with the uppercase letter,
+    /// the same way notcurses reports them.
+    Char(char),
+
+    /// A synthesized key.
+    Key(NcKey),
+}
+
+/// A single key press, together with the modifiers held.
+///
+/// It's parsed from strings in the emacs style, where the key is preceded
+/// by any of the prefixes `C-` (ctrl), `M-` or `A-` (alt) and `S-` (shift),
+/// e.g. `"C-x"`, `"M-Left"`, `"S-F3"`, `"C-M-Enter"`.
+///
+/// The key can be a single character, one of the [`NcKey`] names, or one of
+/// `Space`, `Tab` & `Esc`. The mouse events and [`NcKey::Resize`] are not
+/// key presses, and can't be part of a chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NcChord {
+    /// The key pressed.
+    pub key: NcChordKey,
+    /// The modifiers held.
+    pub modifiers: NcModifiers,
+}
+
+/// A sequence of one or more [`NcChord`]s, pressed one after the other.
+///
+/// It's parsed from strings of chords separated by whitespace,
+/// e.g. `"C-x C-s"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NcKeySequence(pub(crate) Vec<NcChord>);
+
+/// A table of [`NcKeySequence`]s bound to actions of type `A`.
+///
+/// Feed it every input event, and it'll tell whether a sequence was
+/// completed, keeping track of the partially typed ones.
+///
+/// ```
+/// use libnotcurses_sys::*;
+///
+/// # fn main() -> Result<(), NcParseError> {
+/// let mut keymap = NcKeymap::new();
+/// keymap.bind("C-x C-s", "save")?;
+/// keymap.bind("M-Left", "back")?;
+///
+/// let mut input = NcInput::new();
+/// input.id = 'X' as u32;
+/// input.ctrl = true;
+/// assert_eq![NcKeymapMatch::Pending, keymap.feed(&input)];
+/// input.id = 'S' as u32;
+/// assert_eq![NcKeymapMatch::Matched(&mut "save"), keymap.feed(&input)];
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NcKeymap<A> {
+    bindings: Vec<(NcKeySequence, A)>,
+    pending: Vec<NcChord>,
+}
+
+/// The result of feeding an input event to an [`NcKeymap`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum NcKeymapMatch<'a, A> {
+    /// A sequence was completed, returns its action.
+    Matched(&'a mut A),
+
+    /// The chords pressed so far are the start of some bound sequence.
+    Pending,
+
+    /// The chords pressed don't match any bound sequence, and the pending
+    /// ones were discarded, or the input event wasn't a key press.
+    Unbound,
+}
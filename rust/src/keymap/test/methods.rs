@@ -0,0 +1,166 @@
+//! Test `NcChord`, `NcKeySequence` & `NcKeymap` methods.
+
+use crate::{
+    NcChord, NcChordKey, NcInput, NcKey, NcKeySequence, NcKeymap, NcKeymapMatch, NcModifiers,
+    NCKEY_BUTTON1, NCKEY_LEFT, NCKEY_RESIZE,
+};
+
+fn input(id: u32, alt: bool, shift: bool, ctrl: bool) -> NcInput {
+    let mut input = NcInput::new();
+    input.id = id;
+    input.alt = alt;
+    input.shift = shift;
+    input.ctrl = ctrl;
+    input
+}
+
+#[test]
+fn ncchord_from_str() {
+    let chord: NcChord = "C-x".parse().unwrap();
+    assert_eq![NcChordKey::Char('X'), chord.key];
+    assert![chord.modifiers.ctrl && !chord.modifiers.alt && !chord.modifiers.shift];
+
+    let chord: NcChord = "M-Left".parse().unwrap();
+    assert_eq![NcChordKey::Key(NcKey::Left), chord.key];
+    assert![chord.modifiers.alt];
+
+    let chord: NcChord = "S-F3".parse().unwrap();
+    assert_eq![NcChordKey::Key(NcKey::F03), chord.key];
+    assert![chord.modifiers.shift];
+
+    assert_eq![NcChordKey::Char('-'), "C--".parse::<NcChord>().unwrap().key];
+    assert_eq![
+        NcChordKey::Char(' '),
+        "Space".parse::<NcChord>().unwrap().key
+    ];
+    assert_eq![NcChordKey::Char('S'), "S".parse::<NcChord>().unwrap().key];
+
+    assert!["".parse::<NcChord>().is_err()];
+    assert!["C-Foo".parse::<NcChord>().is_err()];
+    assert!["Button1".parse::<NcChord>().is_err()];
+    assert!["Resize".parse::<NcChord>().is_err()];
+}
+
+#[test]
+fn ncchord_display_roundtrip() {
+    for s in &[
+        "C-x",
+        "M-Left",
+        "S-F3",
+        "C-M-S-Enter",
+        "C--",
+        "Space",
+        "a",
+        "X",
+    ] {
+        let chord: NcChord = s.parse().unwrap();
+        assert_eq![*s, chord.to_string()];
+    }
+    let seq: NcKeySequence = "C-x   C-s".parse().unwrap();
+    assert_eq!["C-x C-s", seq.to_string()];
+    assert_eq![2, seq.chords().len()];
+    assert!["  ".parse::<NcKeySequence>().is_err()];
+}
+
+#[test]
+fn ncchord_matches() {
+    let chord: NcChord = "C-x".parse().unwrap();
+    assert![chord.matches(&input('X' as u32, false, false, true))];
+    assert![!chord.matches(&input('X' as u32, true, false, true))];
+    assert![!chord.matches(&input('X' as u32, false, false, false))];
+
+    let chord: NcChord = "M-Left".parse().unwrap();
+    assert![chord.matches(&input(NCKEY_LEFT, true, false, false))];
+    assert![!chord.matches(&input(NCKEY_LEFT, false, false, false))];
+
+    let ni = chord.to_input();
+    assert_eq![NCKEY_LEFT, ni.id];
+    assert![ni.alt && !ni.ctrl && !ni.shift];
+
+    assert_eq![None, NcChord::from_input(&input(0, false, false, false))];
+    assert_eq![
+        None,
+        NcChord::from_input(&input(NCKEY_RESIZE, false, false, false))
+    ];
+    assert_eq![
+        None,
+        NcChord::from_input(&input(NCKEY_BUTTON1, false, false, false))
+    ];
+    assert_eq![
+        Some(NcChord::new(
+            NcChordKey::Char('a'),
+            NcModifiers {
+                alt: true,
+                ..Default::default()
+            }
+        )),
+        NcChord::from_input(&input('a' as u32, true, false, false))
+    ];
+}
+
+#[test]
+fn nckeymap_feed() {
+    let mut keymap = NcKeymap::new();
+    keymap.bind("C-x C-s", 1).unwrap();
+    keymap.bind("C-x C-c", 2).unwrap();
+    keymap.bind("M-Left", 3).unwrap();
+    assert![keymap.bind("C-x Foo", 4).is_err()];
+
+    let ctrl_x = input('X' as u32, false, false, true);
+    let ctrl_s = input('S' as u32, false, false, true);
+    let plain_s = input('s' as u32, false, false, false);
+
+    assert_eq![NcKeymapMatch::Pending, keymap.feed(&ctrl_x)];
+    assert_eq![1, keymap.pending().len()];
+    // non key events leave the pending chords alone
+    assert_eq![
+        NcKeymapMatch::Unbound,
+        keymap.feed(&input(NCKEY_RESIZE, false, false, false))
+    ];
+    assert_eq![NcKeymapMatch::Matched(&mut 1), keymap.feed(&ctrl_s)];
+    assert![keymap.pending().is_empty()];
+
+    assert_eq![NcKeymapMatch::Pending, keymap.feed(&ctrl_x)];
+    assert_eq![NcKeymapMatch::Unbound, keymap.feed(&plain_s)];
+    assert![keymap.pending().is_empty()];
+
+    assert_eq![
+        NcKeymapMatch::Matched(&mut 3),
+        keymap.feed(&input(NCKEY_LEFT, true, false, false))
+    ];
+    assert_eq![
+        NcKeymapMatch::Unbound,
+        keymap.feed(&input(NCKEY_LEFT, false, false, false))
+    ];
+
+    // rebinding replaces the action
+    keymap.bind("M-Left", 5).unwrap();
+    assert_eq![Some(&5), keymap.get(&"M-Left".parse().unwrap())];
+    assert_eq![Some(5), keymap.unbind(&"M-Left".parse().unwrap())];
+    assert_eq![2, keymap.iter().count()];
+
+    // a chord breaking a sequence is matched on its own
+    keymap.bind("q", 6).unwrap();
+    assert_eq![NcKeymapMatch::Pending, keymap.feed(&ctrl_x)];
+    assert_eq![
+        NcKeymapMatch::Matched(&mut 6),
+        keymap.feed(&input('q' as u32, false, false, false))
+    ];
+    assert_eq![NcKeymapMatch::Pending, keymap.feed(&ctrl_x)];
+    assert_eq![NcKeymapMatch::Pending, keymap.feed(&ctrl_x)];
+    assert_eq![1, keymap.pending().len()];
+}
+
+#[test]
+fn nckeymap_dispatch() {
+    let mut count = 0;
+    {
+        let mut keymap: NcKeymap<Box<dyn FnMut()>> = NcKeymap::new();
+        keymap.bind("C-s", Box::new(|| count += 1)).unwrap();
+        let ctrl_s = input('S' as u32, false, false, true);
+        assert![keymap.dispatch(&ctrl_s)];
+        assert![keymap.dispatch(&ctrl_s)];
+        assert![!keymap.dispatch(&input('s' as u32, false, false, false))];
+    }
+    assert_eq![2, count];
+}
@@ -0,0 +1,4 @@
+//! `NcKeymap` tests.
+
+#[cfg(test)]
+mod methods;
@@ -13,13 +13,12 @@
 //! ```rust
 //! use libnotcurses_sys::*;
 //!
-//! fn main() {
-//!     let nc = Notcurses::without_altscreen();
-//!     let plane = nc.stdplane();
-//!     plane.putstr("hello world");
-//!     nc.render();
-//!     nc.stop();
-//! }
+//! fn main() -> NcResult<()> {
+//!     let nc = FullMode::without_altscreen()?;
+//!     nc.stdplane().putstr("hello world")?;
+//!     nc.render()?;
+//!     Ok(())
+//! } // the context is stopped here, when `nc` is dropped
 //! ```
 //!
 //! You can also use the C API functions directly over the constructed types.
@@ -37,7 +36,7 @@
 //!
 //! fn main() {
 //!     let options = NotcursesOptions::with_flags(NCOPTION_NO_ALTERNATE_SCREEN);
-//!     let nc = Notcurses::with_options(&options);
+//!     let nc = Notcurses::with_options(&options).unwrap();
 //!     unsafe {
 //!         let plane = notcurses_stdplane(nc);
 //!         ncplane_putstr(&mut *plane, "hello world");
@@ -77,13 +76,19 @@
 //! There are several common patterns in Rust that this library doesn't employ,
 //! and focuses instead on remaining at a very close distance to the C API.
 //!
-//! 1. There are no Drop trait implementations, therefore you must manually stop
-//! each context before it goes out of scope ([Notcurses], [NcDirect]), and
-//! should manually destroy [NcPlane]s, [NcMenu]s… when no longer needed.
+//! 1. Only a few wrappers implement the Drop trait: [FullMode] and
+//!    `NcHeadless` stop their [Notcurses] context, [NcPlaneOwned] and
+//!    [NcPile] destroy their planes, [NcStatsOwned] frees its stats, [NcFile]
+//!    closes its file, and the guards (e.g. [NcCursorGuard], [NcMouseGuard],
+//!    [NcDirectPushGuard]) restore the state of the terminal. Otherwise you
+//!    must manually stop each raw context before it goes out of scope
+//!    ([Notcurses], [NcDirect]), and should manually destroy [NcMenu]s… when
+//!    no longer needed.
 //!
-//! 2. Instead of handling errors with `Result` (as customary in Rust),
-//!   several functions return an [NcResult] with a value of [NCRESULT_ERR],
-//!   (as customary in C), or [NCRESULT_OK].
+//! 2. While the safe methods return an [NcResult] with an [NcError] on
+//!    failure (as customary in Rust), the C API functions and the manually
+//!    reimplemented static inline functions return an [NcIntResult] with a
+//!    value of [NCRESULT_ERR] (as customary in C), or [NCRESULT_OK].
 //!
 //! The [notcurses]() crate overcomes this limitations by using higher level
 //! abstractions, while pulling itself apart from the C API, in ways this
@@ -112,40 +117,56 @@ mod bindings;
 #[doc(inline)]
 pub use bindings::*;
 
+mod capabilities;
 mod cells;
 mod channel;
 mod direct;
 mod error;
 mod file;
+mod guard;
+#[cfg(feature = "headless")]
+mod headless;
 mod input;
 mod key;
 mod keycodes;
+mod keymap;
 mod macros;
 mod metric;
 mod notcurses;
 mod palette;
+mod pile;
 mod pixel;
 mod plane;
+mod signal;
 mod stats;
 mod time;
+mod version;
 mod visual;
 mod widgets;
 
+pub use capabilities::*;
 pub use cells::*;
 pub use channel::*;
 pub use direct::*;
 pub use error::*;
 pub use file::*;
+pub use guard::*;
+#[cfg(feature = "headless")]
+pub use headless::*;
 pub use input::*;
 pub use key::*;
 pub use keycodes::*;
+pub use keymap::*;
 pub use macros::*;
 pub use metric::*;
 pub use notcurses::*;
 pub use palette::*;
+pub use pile::*;
 pub use pixel::*;
 pub use plane::*;
+pub use signal::*;
 pub use stats::*;
 pub use time::*;
+pub use version::*;
 pub use visual::*;
 pub use widgets::*;
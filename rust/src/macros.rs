@@ -1,5 +1,52 @@
 //! Macros
 
+// NcPlane Macros --------------------------------------------------------------
+
+/// Writes a formatted string to the current location of an
+/// [`NcPlane`][crate::NcPlane], using the current style.
+///
+/// Returns an [`NcResult`][crate::NcResult] with the number of columns the
+/// cursor was advanced. See [`NcPlane::printf`][crate::NcPlane#method.printf].
+#[macro_export]
+macro_rules! ncplane_printf {
+    ($plane:expr, $($arg:tt)*) => {
+        $crate::NcPlane::printf($plane, format_args!($($arg)*))
+    };
+}
+
+/// Writes a formatted string to the location `$y`, `$x` of an
+/// [`NcPlane`][crate::NcPlane], using the current style.
+///
+/// See [`NcPlane::printf_yx`][crate::NcPlane#method.printf_yx].
+#[macro_export]
+macro_rules! ncplane_printf_yx {
+    ($plane:expr, $y:expr, $x:expr, $($arg:tt)*) => {
+        $crate::NcPlane::printf_yx($plane, $y, $x, format_args!($($arg)*))
+    };
+}
+
+/// Writes a formatted string to the row `$y` of an
+/// [`NcPlane`][crate::NcPlane], aligned according to `$align`.
+///
+/// See [`NcPlane::printf_aligned`][crate::NcPlane#method.printf_aligned].
+#[macro_export]
+macro_rules! ncplane_printf_aligned {
+    ($plane:expr, $y:expr, $align:expr, $($arg:tt)*) => {
+        $crate::NcPlane::printf_aligned($plane, $y, $align, format_args!($($arg)*))
+    };
+}
+
+/// Writes a formatted string to the current location of an
+/// [`NcPlane`][crate::NcPlane], retaining the styling of each cell.
+///
+/// See [`NcPlane::printf_stained`][crate::NcPlane#method.printf_stained].
+#[macro_export]
+macro_rules! ncplane_printf_stained {
+    ($plane:expr, $($arg:tt)*) => {
+        $crate::NcPlane::printf_stained($plane, format_args!($($arg)*))
+    };
+}
+
 // General Utility Macros ------------------------------------------------------
 
 /// Sleeps for $ms milliseconds.
@@ -0,0 +1,169 @@
+//! `NcMetric` methods and associated functions.
+
+use core::fmt::{self, Write};
+
+use crate::NcMetric;
+
+// The prefixes for values >= mult, 10^21-1 encompasses 2^64-1.
+const PREFIXES: [char; 8] = ['K', 'M', 'G', 'T', 'P', 'E', 'Z', 'Y'];
+
+// The prefixes for values < 1.
+const SUBPREFIXES: [char; 8] = ['m', 'µ', 'n', 'p', 'f', 'a', 'z', 'y'];
+
+/// # `NcMetric` Constructors
+impl NcMetric {
+    /// New `NcMetric` for `val / decimal`, scaled by powers of `mult`,
+    /// and followed by `uprefix` when a prefix is used.
+    ///
+    /// Returns `None` if `decimal` or `mult` are 0,
+    /// or if `decimal` is bigger than `u64::MAX / 10`.
+    pub fn new(val: u64, decimal: u64, mult: u64, uprefix: Option<char>) -> Option<Self> {
+        if decimal == 0 || mult == 0 || decimal > u64::MAX / 10 {
+            return None;
+        }
+        Some(Self {
+            val,
+            decimal,
+            mult,
+            uprefix,
+            omitdec: false,
+            places: 2,
+            padded: false,
+        })
+    }
+
+    /// New `NcMetric` using the standard (SI) prefixes, like `qprefix()`.
+    ///
+    /// E.g. `1.50K`.
+    pub fn si(val: u64) -> Self {
+        Self::new(val, 1, 1000, None).unwrap()
+    }
+
+    /// New `NcMetric` using the binary (IEC) prefixes with the `i` suffix,
+    /// like `bprefix()`.
+    ///
+    /// E.g. `1.50Ki`.
+    pub fn iec(val: u64) -> Self {
+        Self::new(val, 1, 1024, Some('i')).unwrap()
+    }
+
+    /// New `NcMetric` using the binary (IEC) prefixes without the `i` suffix,
+    /// like `iprefix()`.
+    ///
+    /// E.g. `1.50K`.
+    pub fn iec_short(val: u64) -> Self {
+        Self::new(val, 1, 1024, None).unwrap()
+    }
+}
+
+/// # `NcMetric` Methods
+impl NcMetric {
+    /// Omits the decimal places when the scaled value is an integer.
+    pub fn omit_decimals(mut self) -> Self {
+        self.omitdec = true;
+        self
+    }
+
+    /// Sets the number of decimal places (2 by default).
+    pub fn places(mut self, places: usize) -> Self {
+        self.places = places;
+        self
+    }
+
+    /// Right-aligns the output to [`columns`][NcMetric#method.columns].
+    pub fn padded(mut self) -> Self {
+        self.padded = true;
+        self
+    }
+
+    /// Returns the maximum number of columns the output can use.
+    ///
+    /// With the default 2 decimal places this is
+    /// [`NCMETRIC_PREFIXCOLUMNS`][crate::NCMETRIC_PREFIXCOLUMNS] for a `mult`
+    /// of 1000, [`NCMETRIC_IPREFIXCOLUMNS`][crate::NCMETRIC_IPREFIXCOLUMNS]
+    /// for 1024, and [`NCMETRIC_BPREFIXCOLUMNS`][crate::NCMETRIC_BPREFIXCOLUMNS]
+    /// for 1024 with the `i` suffix.
+    pub fn columns(&self) -> usize {
+        let mut digits = 1;
+        let mut max = self.mult.saturating_sub(1);
+        while max >= 10 {
+            max /= 10;
+            digits += 1;
+        }
+        let decimals = if self.places > 0 { self.places + 1 } else { 0 };
+        digits + decimals + 1 + self.uprefix.map_or(0, |_| 1)
+    }
+
+    // Writes the unpadded output.
+    fn write_to<W: Write>(&self, w: &mut W) -> fmt::Result {
+        let (mut val, decimal, mult) = (self.val, self.decimal, self.mult);
+        let places = self.places;
+
+        let mut consumed = 0;
+        let mut dv = mult;
+        if decimal <= val || val == 0 {
+            while val / decimal >= dv && consumed < PREFIXES.len() {
+                dv *= mult;
+                consumed += 1;
+                // near overflow--can't scale dv again
+                if u64::MAX / dv < mult {
+                    break;
+                }
+            }
+        } else {
+            while val < decimal && consumed < SUBPREFIXES.len() {
+                val = val.wrapping_mul(mult);
+                consumed += 1;
+                if u64::MAX / dv < mult {
+                    break;
+                }
+            }
+        }
+
+        // if consumed == 0, dv must equal mult
+        if dv != mult {
+            if (val / decimal) / dv > 0 {
+                consumed += 1;
+            } else {
+                dv /= mult;
+            }
+            val /= decimal;
+            if self.omitdec && val % dv == 0 {
+                write!(w, "{}", val / dv)?;
+            } else {
+                write!(w, "{:.*}", places, val as f64 / dv as f64)?;
+            }
+            // with a tiny `mult` we can run out of prefixes
+            if let Some(prefix) = PREFIXES.get(consumed - 1) {
+                w.write_char(*prefix)?;
+            }
+        } else {
+            // unscaled output, or scaled down by the subprefixes
+            if self.omitdec && val % decimal == 0 {
+                write!(w, "{}", val / decimal)?;
+            } else {
+                write!(w, "{:.*}", places, val as f64 / decimal as f64)?;
+            }
+            if consumed == 0 {
+                return Ok(());
+            }
+            w.write_char(SUBPREFIXES[consumed - 1])?;
+        }
+        if let Some(uprefix) = self.uprefix {
+            w.write_char(uprefix)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for NcMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::with_capacity(self.columns() + 1);
+        self.write_to(&mut s)?;
+        if self.padded && f.width().is_none() {
+            write!(f, "{:>1$}", s, self.columns())
+        } else {
+            f.pad(&s)
+        }
+    }
+}
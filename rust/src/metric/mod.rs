@@ -0,0 +1,85 @@
+//! `NcMetric`
+
+// functions already exported by bindgen : 1
+// ------------------------------------------
+// (#) test: 1 / 0
+// ------------------------------------------
+// # ncmetric
+//
+// functions manually reimplemented: 1
+// ------------------------------------------
+// (+) done: 1 / 0
+// (#) test: 1 / 0
+// ------------------------------------------
+// # ncmetric                  // NcMetric
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+// TODO: clarify, update and visibilize doc-comments
+
+// The number of columns is one fewer, as the STRLEN expressions must leave
+// an extra byte open in case 'µ' (U+00B5, 0xC2 0xB5) shows up.
+
+// This is the true number of columns;
+//
+// to set up a printf()-style maximum field width,
+// you should use [IB]PREFIXFMT (see below).
+pub const NCMETRIC_PREFIXCOLUMNS: u32 = crate::bindings::ffi::PREFIXCOLUMNS;
+
+// The maximum number of columns used by a mult == 1000 (standard)
+// ncmetric() call.
+pub const NCMETRIC_BPREFIXCOLUMNS: u32 = crate::bindings::ffi::BPREFIXCOLUMNS;
+
+// IPREFIXCOLUMNS is the maximum number of columns used by a mult == 1024
+// (digital information) ncmetric().
+pub const NCMETRIC_IPREFIXCOLUMNS: u32 = crate::bindings::ffi::IPREFIXCOLUMNS;
+
+//
+// Does not include a '\0' (xxx.xxU)
+pub const NCMETRIC_PREFIXSTRLEN: u32 = crate::bindings::ffi::PREFIXSTRLEN;
+
+// The maximum number of columns used by a mult == 1024 call making use of
+// the 'i' suffix.
+// Does not include a '\0' (xxxx.xxUi), i == prefix
+pub const NCMETRIC_BPREFIXSTRLEN: u32 = crate::bindings::ffi::BPREFIXSTRLEN;
+
+// Does not include a '\0' (xxxx.xxU)
+pub const NCMETRIC_IPREFIXSTRLEN: u32 = crate::bindings::ffi::IPREFIXSTRLEN;
+
+// TODO:?
+// WCHAR_MAX_UTF8BYTES
+
+/// A number formatted in a human readable way, using metric prefixes.
+///
+/// This is a pure Rust reimplementation of [`ncmetric`][crate::ncmetric].
+///
+/// `val / decimal` is scaled by powers of `mult` (almost always 1000 or 1024)
+/// and printed with the corresponding prefix: `KMGTPEZY` for big numbers,
+/// and `mµnpfazy` for small ones. If a prefix is used, it's followed by
+/// the `uprefix` character, if any (`i` for kibibytes, basically).
+///
+/// It's displayed using the [`Display`][core::fmt::Display] trait, which
+/// also honours any width and alignment given by the format string.
+///
+/// # Example
+///
+/// ```
+/// use libnotcurses_sys::NcMetric;
+///
+/// assert_eq!["1.50K", NcMetric::si(1500).to_string()];
+/// assert_eq!["2Ki", NcMetric::iec(2048).omit_decimals().to_string()];
+/// assert_eq![" 12.30µ", NcMetric::new(123, 10_000_000, 1000, None).unwrap().padded().to_string()];
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NcMetric {
+    pub(crate) val: u64,
+    pub(crate) decimal: u64,
+    pub(crate) mult: u64,
+    pub(crate) uprefix: Option<char>,
+    pub(crate) omitdec: bool,
+    pub(crate) places: usize,
+    pub(crate) padded: bool,
+}
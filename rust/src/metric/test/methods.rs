@@ -0,0 +1,149 @@
+//! Test `NcMetric` methods and associated functions.
+
+use std::ffi::CStr;
+
+use crate::{NcMetric, NCMETRIC_BPREFIXSTRLEN};
+
+// Formats with the C ncmetric.
+fn c_ncmetric(val: u64, decimal: u64, omitdec: bool, mult: u64, uprefix: char) -> String {
+    let mut buf = [0 as libc::c_char; NCMETRIC_BPREFIXSTRLEN as usize + 1];
+    unsafe {
+        let res = crate::ncmetric(
+            val,
+            decimal,
+            buf.as_mut_ptr(),
+            omitdec as i32,
+            mult,
+            uprefix as i32,
+        );
+        assert![!res.is_null()];
+        CStr::from_ptr(res).to_string_lossy().into_owned()
+    }
+}
+
+// Formats with NcMetric, using the same arguments as ncmetric.
+fn metric(val: u64, decimal: u64, omitdec: bool, mult: u64, uprefix: char) -> String {
+    let uprefix = if uprefix == '\0' { None } else { Some(uprefix) };
+    let m = NcMetric::new(val, decimal, mult, uprefix).unwrap();
+    if omitdec {
+        m.omit_decimals().to_string()
+    } else {
+        m.to_string()
+    }
+}
+
+#[test]
+fn ncmetric_corner_ints() {
+    assert_eq!["0.00", metric(0, 1, false, 1000, '\0')];
+    assert_eq!["0.00", metric(0, 1, false, 1024, 'i')]; // no suffix on < mult
+    assert_eq!["1.00", metric(1, 1, false, 1000, '\0')];
+    assert_eq!["0", metric(0, 1, true, 1024, 'i')];
+    assert_eq!["999", metric(999, 1, true, 1000, '\0')];
+    assert_eq!["1K", metric(1000, 1, true, 1000, '\0')];
+    assert_eq!["1Ki", metric(1000, 1, true, 1000, 'i')];
+    assert_eq!["1000", metric(1000, 1, true, 1024, 'i')];
+    assert_eq!["1.02K", metric(1023, 1, true, 1000, '\0')];
+    assert_eq!["1023", metric(1023, 1, true, 1024, 'i')];
+    assert_eq!["1Ki", metric(1024, 1, true, 1024, 'i')];
+    assert_eq!["1.00Ki", metric(1025, 1, false, 1024, 'i')];
+    assert_eq!["1.00Ki", metric(1025, 1, true, 1024, 'i')];
+    assert_eq!["4.10K", metric(4096, 1, true, 1000, '\0')];
+    assert_eq!["4.00Ki", metric(4096, 1, false, 1024, 'i')];
+    assert_eq!["4Ki", metric(4096, 1, true, 1024, 'i')];
+}
+
+#[test]
+fn ncmetric_max_ints() {
+    assert_eq!["9.22E", metric(i64::MAX as u64, 1, false, 1000, '\0')];
+    assert_eq!["18.45E", metric(u64::MAX, 1, false, 1000, '\0')];
+    assert_eq!["16.00Ei", metric(u64::MAX, 1, false, 1024, 'i')];
+}
+
+#[test]
+fn ncmetric_powers_of_ten() {
+    let suffixes = ["", "K", "M", "G", "T", "P", "E"];
+    for i in 0..20 {
+        let val = 10_u64.pow(i);
+        let gold = 10_u64.pow(i % 3);
+        let suffix = suffixes[i as usize / 3];
+        assert_eq![
+            format!("{}.00{}", gold, suffix),
+            NcMetric::si(val).to_string()
+        ];
+        assert_eq![
+            format!("{}{}", gold, suffix),
+            NcMetric::si(val).omit_decimals().to_string()
+        ];
+    }
+}
+
+#[test]
+fn ncmetric_subprefixes() {
+    assert_eq!["500.00m", metric(1, 2, false, 1000, '\0')];
+    assert_eq!["12.30µ", metric(123, 10_000_000, false, 1000, '\0')];
+    assert_eq!["1n", metric(1, 1_000_000_000, true, 1000, '\0')];
+    assert_eq!["1ni", metric(1, 1_000_000_000, true, 1000, 'i')];
+    assert_eq![
+        "250.00µs",
+        format!("{}s", NcMetric::new(250, 1_000_000, 1000, None).unwrap())
+    ];
+}
+
+#[test]
+fn ncmetric_places_and_padding() {
+    assert_eq![7, NcMetric::si(0).columns()];
+    assert_eq![8, NcMetric::iec_short(0).columns()];
+    assert_eq![9, NcMetric::iec(0).columns()];
+    assert_eq![4, NcMetric::si(0).places(0).columns()];
+
+    assert_eq!["1.5K", NcMetric::si(1500).places(1).to_string()];
+    assert_eq!["2K", NcMetric::si(1500).places(0).to_string()];
+    assert_eq!["  1.50K", NcMetric::si(1500).padded().to_string()];
+    assert_eq!["   1.00Ki", NcMetric::iec(1024).padded().to_string()];
+    assert_eq![
+        " 12.30µ",
+        NcMetric::new(123, 10_000_000, 1000, None)
+            .unwrap()
+            .padded()
+            .to_string()
+    ];
+    assert_eq!["1.50K   |", format!("{:<8}|", NcMetric::si(1500))];
+
+    assert_eq![None, NcMetric::new(1, 0, 1000, None)];
+    assert_eq![None, NcMetric::new(1, 1, 0, None)];
+    assert_eq![None, NcMetric::new(1, u64::MAX, 1000, None)];
+}
+
+#[test]
+fn ncmetric_c_parity() {
+    let mut vals = vec![0, 1, 2, 9, 10, 99, 999, 1000, 1001, 1023, 1024, 1025, 4096];
+    let mut pow = 1_u64;
+    while let Some(next) = pow.checked_mul(7) {
+        vals.extend(&[next - 1, next, next + 1]);
+        pow = next;
+    }
+    vals.extend(&[u64::MAX - 1, u64::MAX]);
+
+    for &val in &vals {
+        for &(decimal, mult, uprefix) in &[
+            (1, 1000, '\0'),
+            (1, 1024, '\0'),
+            (1, 1024, 'i'),
+            (10, 1000, '\0'),
+            (1000, 1024, 'i'),
+        ] {
+            for &omitdec in &[false, true] {
+                assert_eq![
+                    c_ncmetric(val, decimal, omitdec, mult, uprefix),
+                    metric(val, decimal, omitdec, mult, uprefix),
+                    "val {} decimal {} omitdec {} mult {} uprefix {:?}",
+                    val,
+                    decimal,
+                    omitdec,
+                    mult,
+                    uprefix
+                ];
+            }
+        }
+    }
+}
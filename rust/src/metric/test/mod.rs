@@ -0,0 +1,4 @@
+//! `NcMetric` tests
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,221 @@
+//! `NotcursesBuilder`
+
+use core::ptr::{null, null_mut};
+
+use crate::{
+    error::{nc_cstring, nc_ptr},
+    notcurses_init, FullMode, NcFile, NcLogLevelKind, NcMargins, NcResult, NotcursesOptions,
+    NCOPTION_INHIBIT_SETLOCALE, NCOPTION_NO_ALTERNATE_SCREEN, NCOPTION_NO_FONT_CHANGES,
+    NCOPTION_NO_QUIT_SIGHANDLERS, NCOPTION_NO_WINCH_SIGHANDLER, NCOPTION_SUPPRESS_BANNERS,
+    NCOPTION_VERIFY_SIXEL,
+};
+
+/// A builder for a [`FullMode`] context, covering every [`NotcursesOptions`] field.
+///
+/// ```no_run
+/// use libnotcurses_sys::*;
+///
+/// fn main() -> NcResult<()> {
+///     let nc = FullMode::builder()
+///         .loglevel(NcLogLevelKind::Warning)
+///         .margins(1, 2, 1, 2)
+///         .no_alternate_screen(true)
+///         .suppress_banners(true)
+///         .build()?;
+///     nc.render()?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct NotcursesBuilder {
+    loglevel: NcLogLevelKind,
+    margin_t: i32,
+    margin_r: i32,
+    margin_b: i32,
+    margin_l: i32,
+    flags: u64,
+    termtype: Option<String>,
+    renderfp: Option<NcFile>,
+}
+
+impl Default for NotcursesBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # `NotcursesBuilder` Constructors
+impl NotcursesBuilder {
+    /// New NotcursesBuilder, with the same defaults as the C library.
+    pub fn new() -> Self {
+        Self {
+            loglevel: NcLogLevelKind::Silent,
+            margin_t: 0,
+            margin_r: 0,
+            margin_b: 0,
+            margin_l: 0,
+            flags: 0,
+            termtype: None,
+            renderfp: None,
+        }
+    }
+}
+
+/// # `NotcursesBuilder` methods
+impl NotcursesBuilder {
+    /// Sets the [`NcLogLevelKind`].
+    ///
+    /// Progressively higher log levels result in more logging to stderr. By
+    /// default, nothing is printed to stderr once fullscreen service begins.
+    pub fn loglevel(mut self, loglevel: NcLogLevelKind) -> Self {
+        self.loglevel = loglevel;
+        self
+    }
+
+    /// Sets all the margins (top, right, bottom, left).
+    ///
+    /// If all are 0 (default), we will render to the entirety of the screen.
+    /// If the screen is too small, we do what we can.
+    pub fn margins(self, top: i32, right: i32, bottom: i32, left: i32) -> Self {
+        self.margin_top(top)
+            .margin_right(right)
+            .margin_bottom(bottom)
+            .margin_left(left)
+    }
+
+    /// Sets all the margins from an [`NcMargins`].
+    pub fn margins_from(self, margins: NcMargins) -> Self {
+        self.margins(margins.top, margins.right, margins.bottom, margins.left)
+    }
+
+    /// Sets the top margin.
+    pub fn margin_top(mut self, margin: i32) -> Self {
+        self.margin_t = margin;
+        self
+    }
+
+    /// Sets the right margin.
+    pub fn margin_right(mut self, margin: i32) -> Self {
+        self.margin_r = margin;
+        self
+    }
+
+    /// Sets the bottom margin.
+    pub fn margin_bottom(mut self, margin: i32) -> Self {
+        self.margin_b = margin;
+        self
+    }
+
+    /// Sets the left margin.
+    pub fn margin_left(mut self, margin: i32) -> Self {
+        self.margin_l = margin;
+        self
+    }
+
+    /// Sets all the flags at once, replacing any previously set.
+    ///
+    /// See the individual flag methods.
+    pub fn flags(mut self, flags: u64) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Sets or unsets the [`NCOPTION_INHIBIT_SETLOCALE`] flag.
+    pub fn inhibit_setlocale(self, set: bool) -> Self {
+        self.flag(NCOPTION_INHIBIT_SETLOCALE, set)
+    }
+
+    /// Sets or unsets the [`NCOPTION_NO_ALTERNATE_SCREEN`] flag.
+    pub fn no_alternate_screen(self, set: bool) -> Self {
+        self.flag(NCOPTION_NO_ALTERNATE_SCREEN, set)
+    }
+
+    /// Sets or unsets the [`NCOPTION_NO_FONT_CHANGES`] flag.
+    pub fn no_font_changes(self, set: bool) -> Self {
+        self.flag(NCOPTION_NO_FONT_CHANGES, set)
+    }
+
+    /// Sets or unsets the [`NCOPTION_NO_QUIT_SIGHANDLERS`] flag.
+    pub fn no_quit_sighandlers(self, set: bool) -> Self {
+        self.flag(NCOPTION_NO_QUIT_SIGHANDLERS, set)
+    }
+
+    /// Sets or unsets the [`NCOPTION_NO_WINCH_SIGHANDLER`] flag.
+    pub fn no_winch_sighandler(self, set: bool) -> Self {
+        self.flag(NCOPTION_NO_WINCH_SIGHANDLER, set)
+    }
+
+    /// Sets or unsets the [`NCOPTION_SUPPRESS_BANNERS`] flag.
+    pub fn suppress_banners(self, set: bool) -> Self {
+        self.flag(NCOPTION_SUPPRESS_BANNERS, set)
+    }
+
+    /// Sets or unsets the [`NCOPTION_VERIFY_SIXEL`] flag.
+    pub fn verify_sixel(self, set: bool) -> Self {
+        self.flag(NCOPTION_VERIFY_SIXEL, set)
+    }
+
+    /// Sets the terminal type, instead of using the `TERM` environment variable.
+    pub fn termtype(mut self, termtype: &str) -> Self {
+        self.termtype = Some(termtype.to_owned());
+        self
+    }
+
+    /// Sets a file where every rendered frame will be mirrored to.
+    ///
+    /// The file will be owned by the context, and closed after it's stopped.
+    pub fn renderfp(mut self, renderfp: NcFile) -> Self {
+        self.renderfp = Some(renderfp);
+        self
+    }
+
+    /// Returns the [`NotcursesOptions`] with the current configuration.
+    ///
+    /// The `termtype` and `renderfp` fields are always null, since the
+    /// returned struct can't keep them alive.
+    pub fn options(&self) -> NotcursesOptions {
+        NotcursesOptions::with_all_options(
+            self.loglevel,
+            self.margin_t,
+            self.margin_r,
+            self.margin_b,
+            self.margin_l,
+            self.flags,
+        )
+    }
+
+    /// Initializes the new [`FullMode`] context.
+    pub fn build(self) -> NcResult<FullMode> {
+        let termtype = match &self.termtype {
+            Some(termtype) => Some(nc_cstring("notcurses_init", termtype)?),
+            None => None,
+        };
+
+        let mut options = self.options();
+        options.termtype = termtype.as_ref().map_or(null(), |t| t.as_ptr());
+        if let Some(renderfp) = &self.renderfp {
+            options.renderfp = renderfp.as_nc_ptr();
+        }
+
+        let nc = nc_ptr("notcurses_init", unsafe {
+            notcurses_init(&options, null_mut())
+        })?;
+
+        let nc = unsafe { &mut *nc.as_ptr() };
+        Ok(match self.renderfp {
+            Some(renderfp) => unsafe { FullMode::from_raw_with_renderfp(nc, renderfp) },
+            None => unsafe { FullMode::from_raw(nc) },
+        })
+    }
+
+    // private methods --
+
+    fn flag(mut self, flag: u64, set: bool) -> Self {
+        if set {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+        self
+    }
+}
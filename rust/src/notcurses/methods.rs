@@ -1,9 +1,23 @@
 //! `Notcurses*` methods and associated functions.
 
-use core::ptr::{null, null_mut};
+use core::{
+    fmt,
+    ptr::{null, null_mut, NonNull},
+    slice,
+    str::FromStr,
+    time::Duration,
+};
+use std::{
+    ffi::CString,
+    io::{self, Write},
+};
 
 use crate::{
-    notcurses_init, NcLogLevel, NcPlane, NcResult, Notcurses, NotcursesOptions,
+    error::{nc_ptr, nc_result},
+    notcurses_getc, notcurses_init,
+    plane::extra::NcPlaneExtra,
+    NcEvent, NcFile, NcInput, NcLogLevelKind, NcMargins, NcParseError, NcPlane, NcPlaneId,
+    NcPlaneRef, NcResult, NcSignalSet, NcTime, Notcurses, NotcursesOptions,
     NCOPTION_NO_ALTERNATE_SCREEN, NCOPTION_SUPPRESS_BANNERS,
 };
 
@@ -11,17 +25,17 @@ use crate::{
 impl NotcursesOptions {
     /// New NotcursesOptions.
     pub fn new() -> Self {
-        Self::with_all_options(0, 0, 0, 0, 0, 0)
+        Self::with_all_options(NcLogLevelKind::Silent, 0, 0, 0, 0, 0)
     }
 
     /// New NotcursesOptions, with margins.
     pub fn with_margins(top: i32, right: i32, bottom: i32, left: i32) -> Self {
-        Self::with_all_options(0, top, right, bottom, left, 0)
+        Self::with_all_options(NcLogLevelKind::Silent, top, right, bottom, left, 0)
     }
 
     /// New NotcursesOptions, with flags.
     pub fn with_flags(flags: u64) -> Self {
-        Self::with_all_options(0, 0, 0, 0, 0, flags)
+        Self::with_all_options(NcLogLevelKind::Silent, 0, 0, 0, 0, flags)
     }
 
     /// New NotcursesOptions, with all the options.
@@ -55,8 +69,10 @@ impl NotcursesOptions {
     ///   - [`NCOPTION_NO_WINCH_SIGHANDLER`][crate::NCOPTION_NO_WINCH_SIGHANDLER]
     ///   - [`NCOPTION_SUPPRESS_BANNERS`]
     ///
+    /// See also [`NotcursesBuilder`][crate::NotcursesBuilder], which also
+    /// allows to set the `termtype` and the `renderfp` fields.
     pub fn with_all_options(
-        loglevel: NcLogLevel,
+        loglevel: NcLogLevelKind,
         margin_t: i32,
         margin_r: i32,
         margin_b: i32,
@@ -66,7 +82,7 @@ impl NotcursesOptions {
         Self {
             termtype: null(),
             renderfp: null_mut(),
-            loglevel,
+            loglevel: loglevel.into(),
             margin_t,
             margin_r,
             margin_b,
@@ -76,63 +92,307 @@ impl NotcursesOptions {
     }
 }
 
+/// # `NotcursesOptions` Methods
+impl NotcursesOptions {
+    /// Returns the margins.
+    pub fn margins(&self) -> NcMargins {
+        NcMargins::new(self.margin_t, self.margin_r, self.margin_b, self.margin_l)
+    }
+
+    /// Sets the margins.
+    pub fn set_margins(&mut self, margins: NcMargins) {
+        self.margin_t = margins.top;
+        self.margin_r = margins.right;
+        self.margin_b = margins.bottom;
+        self.margin_l = margins.left;
+    }
+}
+
+/// # `NcMargins` Constructors
+impl NcMargins {
+    /// New `NcMargins`.
+    pub const fn new(top: i32, right: i32, bottom: i32, left: i32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// New `NcMargins`, with the same value for all four margins.
+    pub const fn uniform(margin: i32) -> Self {
+        Self::new(margin, margin, margin, margin)
+    }
+}
+
+impl fmt::Display for NcMargins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.top, self.right, self.bottom, self.left
+        )
+    }
+}
+
+/// Parses either a single margin, or four of them separated by commas,
+/// using [`notcurses_lex_margins`][crate::notcurses_lex_margins].
+///
+/// The syntax is checked beforehand, since the lexer prints a message
+/// to stderr on invalid input.
+impl FromStr for NcMargins {
+    type Err = NcParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || NcParseError::new("NcMargins", s);
+        let valid = |m: &str| m.bytes().all(|b| b.is_ascii_digit()) && m.parse::<i32>().is_ok();
+        let count = s.split(',').count();
+        if !(count == 1 || count == 4) || !s.split(',').all(valid) {
+            return Err(err());
+        }
+        let cs = CString::new(s).map_err(|_| err())?;
+        let mut options = NotcursesOptions::new();
+        if unsafe { crate::notcurses_lex_margins(cs.as_ptr(), &mut options) } < 0 {
+            return Err(err());
+        }
+        Ok(options.margins())
+    }
+}
+
 /// # `Notcurses` Constructors
 impl Notcurses {
     /// Returns a Notcurses context (without banners).
-    pub fn new<'a>() -> &'a mut Notcurses {
-        let options = NotcursesOptions::with_flags(NCOPTION_SUPPRESS_BANNERS);
-        unsafe { &mut *notcurses_init(&options, null_mut()) }
+    pub fn new<'a>() -> NcResult<&'a mut Notcurses> {
+        Self::with_options(&NotcursesOptions::with_flags(NCOPTION_SUPPRESS_BANNERS))
     }
 
     /// Returns a Notcurses context, with banners. The default in the C library.
-    pub fn with_banners<'a>() -> &'a mut Notcurses {
-        unsafe { &mut *notcurses_init(&NotcursesOptions::new(), null_mut()) }
+    pub fn with_banners<'a>() -> NcResult<&'a mut Notcurses> {
+        Self::with_options(&NotcursesOptions::new())
     }
 
     /// Returns a Notcurses context, without an alternate screen (nor banners).
-    pub fn without_altscreen<'a>() -> &'a mut Notcurses {
-        let options =
-            NotcursesOptions::with_flags(NCOPTION_NO_ALTERNATE_SCREEN | NCOPTION_SUPPRESS_BANNERS);
-        unsafe { &mut *notcurses_init(&options, null_mut()) }
+    pub fn without_altscreen<'a>() -> NcResult<&'a mut Notcurses> {
+        Self::with_options(&NotcursesOptions::with_flags(
+            NCOPTION_NO_ALTERNATE_SCREEN | NCOPTION_SUPPRESS_BANNERS,
+        ))
     }
 
     /// Returns a Notcurses context, without an alternate screen, with banners.
-    pub fn without_altscreen_nor_banners<'a>() -> &'a mut Notcurses {
-        let options = NotcursesOptions::with_flags(NCOPTION_NO_ALTERNATE_SCREEN);
-        unsafe { &mut *notcurses_init(&options, null_mut()) }
+    pub fn without_altscreen_nor_banners<'a>() -> NcResult<&'a mut Notcurses> {
+        Self::with_options(&NotcursesOptions::with_flags(NCOPTION_NO_ALTERNATE_SCREEN))
     }
 
     /// Returns a Notcurses context, expects [NotcursesOptions].
-    pub fn with_options<'a>(options: &NotcursesOptions) -> &'a mut Notcurses {
-        unsafe { &mut *notcurses_init(options, null_mut()) }
+    ///
+    /// Returns an error if [notcurses_init] fails (e.g. when
+    /// the terminal can't be initialized).
+    pub fn with_options<'a>(options: &NotcursesOptions) -> NcResult<&'a mut Notcurses> {
+        let nc = nc_ptr("notcurses_init", unsafe {
+            notcurses_init(options, null_mut())
+        })?;
+        Ok(unsafe { &mut *nc.as_ptr() })
     }
 }
 
 /// # `Notcurses` methods
 impl Notcurses {
-    /// Returns a mutable reference to the standard [NcPlane] for this terminal.
+    /// Returns a handle to the standard [NcPlane] for this terminal.
     ///
     /// The standard plane always exists, and its origin is always at the
-    /// uppermost, leftmost cell.
-    pub fn stdplane<'a>(&mut self) -> &'a mut NcPlane {
-        unsafe { &mut *crate::notcurses_stdplane(self) }
+    /// uppermost, leftmost cell. It can't outlive this context, and neither
+    /// can the planes bound to it, which don't keep the context borrowed
+    /// beyond that, so it can still be rendered while they're alive.
+    pub fn stdplane(&self) -> NcPlaneRef<'_> {
+        unsafe {
+            NcPlaneRef::from_ptr(NonNull::new_unchecked(crate::notcurses_stdplane(
+                self as *const _ as *mut _,
+            )))
+        }
     }
 
     /// Returns a reference to the standard [NcPlane] for this terminal.
     ///
     /// The standard plane always exists, and its origin is always at the
     /// uppermost, leftmost cell.
-    pub fn stdplane_const<'a>(&self) -> &'a NcPlane {
+    pub fn stdplane_const(&self) -> &NcPlane {
         unsafe { &*crate::notcurses_stdplane_const(self) }
     }
 
+    /// Returns the identifiers of the planes of the standard pile, in z-order,
+    /// from the top to the bottom.
+    ///
+    /// They can be owned by other handles, so only their identifiers are
+    /// returned.
+    pub fn planes(&self) -> Vec<NcPlaneId> {
+        self.stdplane_const().pile_planes()
+    }
+
     /// Destroy the Notcurses context.
-    pub fn stop(&mut self) -> NcResult {
-        unsafe { crate::notcurses_stop(self) }
+    ///
+    /// # Safety
+    ///
+    /// The context must not be used after this call, and must not be owned by
+    /// a [`FullMode`][crate::FullMode], which would stop it again when dropped.
+    /// Prefer calling [`FullMode::stop`][crate::FullMode#method.stop] instead.
+    pub unsafe fn stop(&mut self) -> NcResult<()> {
+        NcPlaneExtra::free(crate::notcurses_stdplane(self));
+        nc_result("notcurses_stop", crate::notcurses_stop(self))?;
+        Ok(())
     }
 
+    /// Renders and rasterizes the standard pile in one shot.
     ///
-    pub fn render(&mut self) -> NcResult {
-        unsafe { crate::notcurses_render(self) }
+    /// It waits for any [`NcPile`][crate::NcPile] being rasterized.
+    pub fn render(&self) -> NcResult<()> {
+        let _lock = crate::pile::raster_lock();
+        nc_result("notcurses_render", unsafe {
+            crate::notcurses_render(self as *const _ as *mut _)
+        })?;
+        Ok(())
+    }
+
+    /// Renders and rasterizes the standard pile, returning the resulting
+    /// bytes instead of writing them to the terminal.
+    ///
+    /// Like [`render`][Notcurses#method.render], it only emits the changes
+    /// since the last rendered frame.
+    pub fn render_to_vec(&self) -> NcResult<Vec<u8>> {
+        let _lock = crate::pile::raster_lock();
+        self.render_to_vec_locked()
+    }
+
+    // render_to_vec, with the raster lock already taken.
+    fn render_to_vec_locked(&self) -> NcResult<Vec<u8>> {
+        let mut buf = null_mut();
+        let mut len = 0;
+        nc_result("notcurses_render_to_buffer", unsafe {
+            crate::notcurses_render_to_buffer(self as *const _ as *mut _, &mut buf, &mut len)
+        })?;
+        if buf.is_null() {
+            return Ok(Vec::new());
+        }
+        unsafe {
+            let vec = slice::from_raw_parts(buf as *const u8, len as usize).to_vec();
+            libc::free(buf as *mut _);
+            Ok(vec)
+        }
+    }
+
+    /// Renders and rasterizes the standard pile, writing the whole resulting
+    /// frame to `writer` instead of to the terminal.
+    ///
+    /// Unlike [`render_to_vec`][Notcurses#method.render_to_vec], the frame is
+    /// written in its entirety, even the parts that didn't change since the
+    /// last one.
+    pub fn render_to_writer<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        // no other pile can be rasterized between rendering and writing
+        let lock = crate::pile::raster_lock();
+        self.render_to_vec_locked()?;
+
+        let (mut buf, mut len) = (null_mut(), 0);
+        let fp = unsafe { libc::open_memstream(&mut buf, &mut len) };
+        if fp.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        let mut file = NcFile::from_libc(fp);
+        let res = self.render_to_file_locked(&mut file);
+        drop(lock);
+        // closing the stream flushes it into `buf`
+        drop(file);
+        let frame = unsafe {
+            let frame = slice::from_raw_parts(buf as *const u8, len).to_vec();
+            libc::free(buf as *mut _);
+            frame
+        };
+        res?;
+        writer.write_all(&frame)
+    }
+
+    /// Writes the last rendered frame, in its entirety, to `file`.
+    ///
+    /// If [`render`][Notcurses#method.render] has not yet been called,
+    /// nothing will be written.
+    pub fn render_to_file(&self, file: &mut NcFile) -> NcResult<()> {
+        let _lock = crate::pile::raster_lock();
+        self.render_to_file_locked(file)
+    }
+
+    // render_to_file, with the raster lock already taken.
+    fn render_to_file_locked(&self, file: &mut NcFile) -> NcResult<()> {
+        nc_result("notcurses_render_to_file", unsafe {
+            crate::notcurses_render_to_file(self as *const _ as *mut _, file.as_nc_ptr())
+        })?;
+        Ok(())
+    }
+
+    // Input -------------------------------------------------------------------
+
+    /// Waits for an input event, with `sigmask` as the signal mask during the
+    /// wait, and returns its raw `char32_t` code, filling in the `input`.
+    ///
+    /// The signals in `sigmask` are blocked while waiting, and the rest can
+    /// interrupt it. With a `timeout` of `None` it waits indefinitely,
+    /// and with a zero `timeout` it doesn't wait at all.
+    ///
+    /// Returns `u32::MAX` if no event was ready before the timeout, on error,
+    /// or when interrupted by a signal. See [`NcEvent`] for a decoded version.
+    pub fn getc(
+        &self,
+        timeout: Option<Duration>,
+        sigmask: &NcSignalSet,
+        input: &mut NcInput,
+    ) -> u32 {
+        let ts = timeout.map(NcTime::from);
+        let mut sigmask = *sigmask;
+        unsafe {
+            notcurses_getc(
+                self as *const _ as *mut _,
+                ts.as_ref().map_or(null(), |ts| ts),
+                sigmask.as_mut_ptr(),
+                input,
+            )
+        }
+    }
+
+    /// Waits for an input event, blocking until one is processed or a signal
+    /// is received.
+    ///
+    /// Returns [`NcEvent::Eof`] on error, or when interrupted by a signal,
+    /// and never returns `None`.
+    pub fn get_event_blocking(&self) -> NcResult<Option<NcEvent>> {
+        self.get_event_masked(None, &NcSignalSet::new())
+    }
+
+    /// Returns the next input event, if any is ready, without blocking.
+    pub fn get_event_nblock(&self) -> NcResult<Option<NcEvent>> {
+        self.get_event_masked(Some(Duration::from_secs(0)), &NcSignalSet::full())
+    }
+
+    /// Waits for an input event, blocking no longer than `timeout`.
+    ///
+    /// Returns `None` if no event was ready before the timeout.
+    pub fn get_event_timeout(&self, timeout: Duration) -> NcResult<Option<NcEvent>> {
+        self.get_event_masked(Some(timeout), &NcSignalSet::full())
+    }
+
+    /// Waits for an input event, blocking no longer than `timeout`, if any,
+    /// and letting any signal not in `sigmask` interrupt the wait.
+    ///
+    /// With a `timeout`, returns `None` if no event was ready, which can't
+    /// be told apart from an error. Without it, returns [`NcEvent::Eof`] on
+    /// error. See [`getc`][Notcurses#method.getc].
+    pub fn get_event_masked(
+        &self,
+        timeout: Option<Duration>,
+        sigmask: &NcSignalSet,
+    ) -> NcResult<Option<NcEvent>> {
+        let mut input = NcInput::new();
+        let id = self.getc(timeout, sigmask, &mut input);
+        if id == u32::MAX && timeout.is_some() {
+            return Ok(None);
+        }
+        NcEvent::from_input(id, &input).map(Some)
     }
 }
@@ -2,10 +2,11 @@
 
 // functions already exported by bindgen : 39
 // ------------------------------------------
+// (W) wrap: 5 / 34
 // (#) unit tests: 10 / 29
 // ------------------------------------------
 //   notcurses_at_yx
-//   notcurses_bottom
+//W  notcurses_bottom
 // # notcurses_canchangecolor
 // # notcurses_canfade
 // # notcurses_canopen_images
@@ -20,9 +21,9 @@
 //   notcurses_getc
 // # notcurses_init
 //   notcurses_inputready_fd
-//   notcurses_lex_blitter
-//   notcurses_lex_margins
-//   notcurses_lex_scalemode
+//W  notcurses_lex_blitter
+//W  notcurses_lex_margins
+//W  notcurses_lex_scalemode
 //   notcurses_mouse_disable
 //   notcurses_mouse_enable
 //   notcurses_palette_size
@@ -36,10 +37,10 @@
 //   notcurses_stdplane
 //   notcurses_stdplane_const
 // # notcurses_stop
-//   notcurses_str_blitter
-//   notcurses_str_scalemode
+//W  notcurses_str_blitter
+//W  notcurses_str_scalemode
 //   notcurses_supported_styles
-//   notcurses_top
+//W  notcurses_top
 //   notcurses_ucs32_to_utf8
 //   notcurses_version
 //   notcurses_version_components
@@ -59,9 +60,13 @@
 #[cfg(test)]
 mod test;
 
+mod builder;
 mod methods;
 mod reimplemented;
+mod wrapped;
+pub use builder::*;
 pub use reimplemented::*;
+pub use wrapped::*;
 
 /// The main struct of the (full mode) TUI library
 ///
@@ -129,6 +134,26 @@ pub const NCOPTION_SUPPRESS_BANNERS: u64 = crate::bindings::ffi::NCOPTION_SUPPRE
 /// to unconditionally test for Sixel support in notcurses_init().
 pub const NCOPTION_VERIFY_SIXEL: u64 = crate::bindings::ffi::NCOPTION_VERIFY_SIXEL as u64;
 
+// NcMargins -------------------------------------------------------------------
+
+/// The margins of the rendering area of a [`Notcurses`] context,
+/// as set in the [`NotcursesOptions`].
+///
+/// It can be parsed from, and is displayed as, a list of margins separated by
+/// commas, in the order `top,right,bottom,left`. When parsing, a single value
+/// can also be used for all four margins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NcMargins {
+    /// The top margin.
+    pub top: i32,
+    /// The right margin.
+    pub right: i32,
+    /// The bottom margin.
+    pub bottom: i32,
+    /// The left margin.
+    pub left: i32,
+}
+
 // NcLogLevel ------------------------------------------------------------------
 
 /// Log level for [`NotcursesOptions`]
@@ -139,6 +164,8 @@ pub const NCOPTION_VERIFY_SIXEL: u64 = crate::bindings::ffi::NCOPTION_VERIFY_SIX
 /// `NCOPTION_SUPPRESS_BANNERS`.
 /// Note that if stderr is connected to the same terminal on which we're
 /// rendering, any kind of logging will disrupt the output.
+///
+/// See also [`NcLogLevelKind`], used by the safe API.
 pub type NcLogLevel = crate::bindings::ffi::ncloglevel_e;
 
 /// this is honestly a bit much
@@ -150,7 +177,7 @@ pub const NCLOGLEVEL_ERROR: NcLogLevel = crate::bindings::ffi::ncloglevel_e_NCLO
 /// we're hanging around, but we've had a horrible fault
 pub const NCLOGLEVEL_FATAL: NcLogLevel = crate::bindings::ffi::ncloglevel_e_NCLOGLEVEL_FATAL;
 
-/// "detailed information
+/// "standard information"
 pub const NCLOGLEVEL_INFO: NcLogLevel = crate::bindings::ffi::ncloglevel_e_NCLOGLEVEL_INFO;
 
 /// print diagnostics immediately related to crashing
@@ -162,8 +189,54 @@ pub const NCLOGLEVEL_SILENT: NcLogLevel = crate::bindings::ffi::ncloglevel_e_NCL
 /// there's probably a better way to do what you want
 pub const NCLOGLEVEL_TRACE: NcLogLevel = crate::bindings::ffi::ncloglevel_e_NCLOGLEVEL_TRACE;
 
-/// "detailed information
+/// "detailed information"
 pub const NCLOGLEVEL_VERBOSE: NcLogLevel = crate::bindings::ffi::ncloglevel_e_NCLOGLEVEL_VERBOSE;
 
 /// you probably don't want what's happening to happen
 pub const NCLOGLEVEL_WARNING: NcLogLevel = crate::bindings::ffi::ncloglevel_e_NCLOGLEVEL_WARNING;
+
+/// Log level for [`NotcursesOptions`], used by the safe API.
+///
+/// See also [`NcLogLevel`], used by the C API.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NcLogLevelKind {
+    /// default. print nothing once fullscreen service begins
+    Silent = NCLOGLEVEL_SILENT,
+
+    /// print diagnostics immediately related to crashing
+    Panic = NCLOGLEVEL_PANIC,
+
+    /// we're hanging around, but we've had a horrible fault
+    Fatal = NCLOGLEVEL_FATAL,
+
+    /// we can't keep doin' this, but we can do other things
+    Error = NCLOGLEVEL_ERROR,
+
+    /// you probably don't want what's happening to happen
+    Warning = NCLOGLEVEL_WARNING,
+
+    /// "standard information"
+    Info = NCLOGLEVEL_INFO,
+
+    /// "detailed information"
+    Verbose = NCLOGLEVEL_VERBOSE,
+
+    /// this is honestly a bit much
+    Debug = NCLOGLEVEL_DEBUG,
+
+    /// there's probably a better way to do what you want
+    Trace = NCLOGLEVEL_TRACE,
+}
+
+impl Default for NcLogLevelKind {
+    fn default() -> Self {
+        NcLogLevelKind::Silent
+    }
+}
+
+impl From<NcLogLevelKind> for NcLogLevel {
+    fn from(level: NcLogLevelKind) -> Self {
+        level as NcLogLevel
+    }
+}
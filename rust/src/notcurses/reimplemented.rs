@@ -3,19 +3,8 @@
 use core::ptr::null;
 
 use crate::{
-    // NOTE: can't use libc::sigset_t with notcurses_getc(()
-    bindings::{sigemptyset, sigfillset, sigset_t},
-    ncplane_dim_yx,
-    notcurses_getc,
-    notcurses_stdplane,
-    notcurses_stdplane_const,
-    NcAlign,
-    NcInput,
-    NcPlane,
-    NcTime,
-    Notcurses,
-    NCALIGN_CENTER,
-    NCALIGN_LEFT,
+    ncplane_dim_yx, notcurses_getc, notcurses_stdplane, notcurses_stdplane_const, NcAlign, NcInput,
+    NcPlane, NcSignalSet, NcTime, Notcurses, NCALIGN_CENTER, NCALIGN_LEFT,
 };
 
 /// return the offset into 'availcols' at which 'cols' ought be output given the requirements of 'align'
@@ -35,34 +24,39 @@ pub fn notcurses_align(availcols: i32, align: NcAlign, cols: i32) -> i32 {
 
 /// 'input' may be NULL if the caller is uninterested in event details.
 /// If no event is ready, returns 0.
-// TODO: use pakr-signals
+///
+/// Returns the raw `char32_t` code, which can be a synthesized `NCKEY_*`
+/// key, or `u32::MAX` on error. See [`NcEvent`][crate::NcEvent] for a
+/// decoded version.
 #[inline]
-pub fn notcurses_getc_nblock(nc: &mut Notcurses, input: &mut NcInput) -> char {
+pub fn notcurses_getc_nblock(nc: &mut Notcurses, input: &mut NcInput) -> u32 {
     unsafe {
-        let mut sigmask = sigset_t { __val: [0; 16] };
-        sigfillset(&mut sigmask);
+        let mut sigmask = NcSignalSet::full();
         let ts = NcTime {
             tv_sec: 0,
             tv_nsec: 0,
         };
-        core::char::from_u32_unchecked(notcurses_getc(nc, &ts, &mut sigmask, input))
+        notcurses_getc(nc, &ts, sigmask.as_mut_ptr(), input)
     }
 }
 
 /// 'input' may be NULL if the caller is uninterested in event details.
 /// Blocks until an event is processed or a signal is received.
+///
+/// Returns the raw `char32_t` code, which can be a synthesized `NCKEY_*`
+/// key, or `u32::MAX` on error. See [`NcEvent`][crate::NcEvent] for a
+/// decoded version.
 #[inline]
-pub fn notcurses_getc_nblocking(nc: &mut Notcurses, input: &mut NcInput) -> char {
+pub fn notcurses_getc_nblocking(nc: &mut Notcurses, input: &mut NcInput) -> u32 {
     unsafe {
-        let mut sigmask = sigset_t { __val: [0; 16] };
-        sigemptyset(&mut sigmask);
-        core::char::from_u32_unchecked(notcurses_getc(nc, null(), &mut sigmask, input))
+        let mut sigmask = NcSignalSet::new();
+        notcurses_getc(nc, null(), sigmask.as_mut_ptr(), input)
     }
 }
 
 /// notcurses_stdplane(), plus free bonus dimensions written to non-NULL y/x!
 #[inline]
-pub fn notcurses_stddim_yx<'a>(nc: &mut Notcurses, y: &mut i32, x: &mut i32) -> &'a mut NcPlane {
+pub fn notcurses_stddim_yx<'a>(nc: &'a mut Notcurses, y: &mut i32, x: &mut i32) -> &'a mut NcPlane {
     unsafe {
         let s = notcurses_stdplane(nc);
         ncplane_dim_yx(s, y, x);
@@ -83,9 +77,10 @@ pub fn notcurses_stddim_yx_const<'a>(nc: &'a Notcurses, y: &mut i32, x: &mut i32
 /// Return our current idea of the terminal dimensions in rows and cols.
 #[inline]
 pub fn notcurses_term_dim_yx(nc: &Notcurses, rows: &mut u32, cols: &mut u32) {
+    let (mut irows, mut icols) = (0, 0);
     unsafe {
-        let mut irows = *rows as i32;
-        let mut icols = *cols as i32;
         ncplane_dim_yx(notcurses_stdplane_const(nc), &mut irows, &mut icols);
     }
+    *rows = irows as u32;
+    *cols = icols as u32;
 }
@@ -0,0 +1,48 @@
+//! Test `NotcursesBuilder`.
+
+use serial_test::serial;
+
+use crate::{
+    FullMode, NcLogLevelKind, NotcursesBuilder, NCLOGLEVEL_WARNING, NCOPTION_NO_ALTERNATE_SCREEN,
+    NCOPTION_SUPPRESS_BANNERS,
+};
+
+#[test]
+#[serial]
+fn notcursesbuilder_options() {
+    let options = NotcursesBuilder::new()
+        .loglevel(NcLogLevelKind::Warning)
+        .margins(1, 2, 3, 4)
+        .no_alternate_screen(true)
+        .suppress_banners(true)
+        .no_alternate_screen(false)
+        .options();
+    assert_eq![NCLOGLEVEL_WARNING, options.loglevel];
+    assert_eq![
+        (1, 2, 3, 4),
+        (
+            options.margin_t,
+            options.margin_r,
+            options.margin_b,
+            options.margin_l
+        )
+    ];
+    assert_eq![NCOPTION_SUPPRESS_BANNERS, options.flags];
+    assert_eq![0, options.flags & NCOPTION_NO_ALTERNATE_SCREEN];
+    assert![options.termtype.is_null()];
+    assert![options.renderfp.is_null()];
+}
+
+#[test]
+#[serial]
+fn notcursesbuilder_build() {
+    let nc = FullMode::builder().suppress_banners(true).build().unwrap();
+    assert_eq![Ok(()), nc.stop()];
+}
+
+#[test]
+#[serial]
+fn notcursesbuilder_termtype_nul() {
+    let res = FullMode::builder().termtype("xterm\0").build();
+    assert_eq![Some("notcurses_init"), res.err().map(|e| e.func())];
+}
@@ -1,4 +1,63 @@
 //! Test `Notcurses` methods and associated functions.
 
-// use crate::Notcurses;
-// use serial_test::serial;
+use core::time::Duration;
+
+use serial_test::serial;
+
+use crate::{FullMode, NcMargins, NcPlane};
+
+#[test]
+#[serial]
+fn notcurses_get_event_nblock() {
+    let nc = FullMode::new().unwrap();
+    // there must be no pending input
+    assert_eq![None, nc.get_event_nblock().unwrap()];
+    assert_eq![
+        None,
+        nc.get_event_timeout(Duration::from_millis(10)).unwrap()
+    ];
+}
+
+#[test]
+#[serial]
+fn notcurses_render_to_vec() {
+    let nc = FullMode::new().unwrap();
+    nc.stdplane().putstr_yx(0, 0, "hello").unwrap();
+    let contains = |frame: &[u8]| frame.windows(5).any(|w| w == b"hello");
+
+    let frame = nc.render_to_vec().unwrap();
+    assert![contains(&frame)];
+    // nothing changed since the last frame
+    assert![!contains(&nc.render_to_vec().unwrap())];
+
+    // the whole frame is written, even if nothing changed
+    let mut writer = Vec::new();
+    nc.render_to_writer(&mut writer).unwrap();
+    assert![contains(&writer)];
+}
+
+#[test]
+#[serial]
+fn notcurses_render_stdplane_children() {
+    let nc = FullMode::new().unwrap();
+    let mut stdplane = nc.stdplane();
+    let mut child = NcPlane::new_bound(&mut stdplane, 1, 1, 2, 2).unwrap();
+    let other = NcPlane::new(&nc, 0, 0, 1, 1).unwrap();
+
+    // the context can be rendered while the planes are alive
+    child.putstr_yx(0, 0, "hi").unwrap();
+    nc.render().unwrap();
+    assert_eq![Some(nc.stdplane().id()), child.parent()];
+    drop(other);
+}
+
+#[test]
+fn ncmargins_lex() {
+    assert_eq![Ok(NcMargins::new(1, 2, 3, 4)), "1,2,3,4".parse()];
+    assert_eq![Ok(NcMargins::uniform(5)), "5".parse()];
+    assert_eq!["1,2,3,4", NcMargins::new(1, 2, 3, 4).to_string()];
+    assert!["1,2".parse::<NcMargins>().is_err()];
+    assert!["1,2,3,4,".parse::<NcMargins>().is_err()];
+    assert!["-1".parse::<NcMargins>().is_err()];
+    assert!["".parse::<NcMargins>().is_err()];
+}
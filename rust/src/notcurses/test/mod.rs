@@ -5,3 +5,9 @@ mod methods;
 
 #[cfg(test)]
 mod reimplemented;
+
+#[cfg(test)]
+mod wrapped;
+
+#[cfg(test)]
+mod builder;
@@ -0,0 +1,19 @@
+//! Test `FullMode` wrapper.
+
+use serial_test::serial;
+
+use crate::FullMode;
+
+#[test]
+#[serial]
+fn fullmode_drop() {
+    let nc = FullMode::new().unwrap();
+    let _ = nc.stdplane_const();
+}
+
+#[test]
+#[serial]
+fn fullmode_stop() {
+    let nc = FullMode::new().unwrap();
+    assert_eq![Ok(()), nc.stop()];
+}
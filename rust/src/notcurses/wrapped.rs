@@ -0,0 +1,130 @@
+//! `FullMode` wrapper struct and traits implementations.
+
+use core::{
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{NcFile, NcResult, Notcurses, NotcursesBuilder, NotcursesOptions};
+
+/// An owned [`Notcurses`] context, that stops itself when dropped.
+///
+/// The context is stopped exactly once, either by calling [`stop`][FullMode#method.stop],
+/// or automatically when it goes out of scope (also while unwinding from a
+/// panic), so the terminal is always restored to its original state.
+///
+/// It dereferences to [`Notcurses`], so all its methods are available.
+///
+/// See also [`NotcursesBuilder`][crate::NotcursesBuilder].
+#[derive(Debug)]
+pub struct FullMode {
+    raw: NonNull<Notcurses>,
+
+    // the file rendered frames are mirrored to, closed after stopping.
+    renderfp: Option<NcFile>,
+}
+
+/// # `FullMode` Constructors
+impl FullMode {
+    /// Returns a Notcurses context (without banners).
+    pub fn new() -> NcResult<Self> {
+        Ok(unsafe { Self::from_raw(Notcurses::new()?) })
+    }
+
+    /// Returns a Notcurses context, with banners. The default in the C library.
+    pub fn with_banners() -> NcResult<Self> {
+        Ok(unsafe { Self::from_raw(Notcurses::with_banners()?) })
+    }
+
+    /// Returns a Notcurses context, without an alternate screen (nor banners).
+    pub fn without_altscreen() -> NcResult<Self> {
+        Ok(unsafe { Self::from_raw(Notcurses::without_altscreen()?) })
+    }
+
+    /// Returns a Notcurses context, without an alternate screen, with banners.
+    pub fn without_altscreen_nor_banners() -> NcResult<Self> {
+        Ok(unsafe { Self::from_raw(Notcurses::without_altscreen_nor_banners()?) })
+    }
+
+    /// Returns a Notcurses context, expects [NotcursesOptions].
+    pub fn with_options(options: &NotcursesOptions) -> NcResult<Self> {
+        Ok(unsafe { Self::from_raw(Notcurses::with_options(options)?) })
+    }
+
+    /// Takes ownership of an already initialized Notcurses context.
+    ///
+    /// # Safety
+    ///
+    /// The context will be stopped when the returned `FullMode` is dropped,
+    /// so it must not be stopped, nor wrapped again, by any other means.
+    pub unsafe fn from_raw(nc: &mut Notcurses) -> Self {
+        Self {
+            raw: NonNull::from(nc),
+            renderfp: None,
+        }
+    }
+
+    /// Takes ownership of an already initialized Notcurses context, and of
+    /// the file it's mirroring the rendered frames to, which will be closed
+    /// after the context is stopped.
+    ///
+    /// # Safety
+    ///
+    /// See [`from_raw`][FullMode#method.from_raw].
+    pub(crate) unsafe fn from_raw_with_renderfp(nc: &mut Notcurses, renderfp: NcFile) -> Self {
+        Self {
+            raw: NonNull::from(nc),
+            renderfp: Some(renderfp),
+        }
+    }
+
+    /// Returns a new [`NotcursesBuilder`][crate::NotcursesBuilder].
+    pub fn builder() -> NotcursesBuilder {
+        NotcursesBuilder::new()
+    }
+}
+
+/// # `FullMode` methods
+impl FullMode {
+    /// Destroys the Notcurses context.
+    ///
+    /// Use this instead of letting it drop when you need to check the result.
+    pub fn stop(self) -> NcResult<()> {
+        let mut this = ManuallyDrop::new(self);
+        let _renderfp = this.renderfp.take();
+        unsafe { this.raw.as_mut().stop() }
+    }
+}
+
+impl Drop for FullMode {
+    fn drop(&mut self) {
+        let _ = unsafe { self.raw.as_mut().stop() };
+    }
+}
+
+impl Deref for FullMode {
+    type Target = Notcurses;
+
+    fn deref(&self) -> &Notcurses {
+        unsafe { self.raw.as_ref() }
+    }
+}
+
+impl DerefMut for FullMode {
+    fn deref_mut(&mut self) -> &mut Notcurses {
+        unsafe { self.raw.as_mut() }
+    }
+}
+
+impl AsRef<Notcurses> for FullMode {
+    fn as_ref(&self) -> &Notcurses {
+        self
+    }
+}
+
+impl AsMut<Notcurses> for FullMode {
+    fn as_mut(&mut self) -> &mut Notcurses {
+        self
+    }
+}
@@ -0,0 +1,237 @@
+//! `NcPile` methods and associated functions.
+
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{
+    error::{nc_ptr, nc_result},
+    NcPile, NcPileIter, NcPlane, NcPlaneId, NcPlaneOptions, NcPlaneOwned, NcResult, Notcurses,
+};
+
+/// # `NcPile` Constructors
+impl<'nc> NcPile<'nc> {
+    /// New `NcPile`, with a new root plane.
+    pub fn new(nc: &'nc Notcurses, y: i32, x: i32, rows: u32, cols: u32) -> NcResult<Self> {
+        Ok(Self::from_root(NcPlane::new(nc, y, x, rows, cols)?))
+    }
+
+    /// New `NcPile`, with a new root plane created with `options`.
+    pub fn with_options(nc: &'nc Notcurses, options: &NcPlaneOptions) -> NcResult<Self> {
+        Ok(Self::from_root(NcPlane::with_options(nc, options)?))
+    }
+
+    /// New `NcPile`, with a new root plane the size of the terminal.
+    pub fn new_termsize(nc: &'nc Notcurses) -> NcResult<Self> {
+        Ok(Self::from_root(NcPlane::new_termsize(nc)?))
+    }
+}
+
+/// # `NcPile` Methods
+impl<'nc> NcPile<'nc> {
+    /// Returns the root plane.
+    pub fn root(&self) -> &NcPlane {
+        &self.root
+    }
+
+    /// Returns the root plane, mutably.
+    pub fn root_mut(&mut self) -> &mut NcPlane {
+        &mut self.root
+    }
+
+    /// Returns the root plane, taking ownership of it.
+    ///
+    /// The rest of the owned planes are destroyed.
+    pub fn into_root(mut self) -> NcPlaneOwned<'nc> {
+        self.planes.clear();
+        self.root
+    }
+
+    // Owned planes ------------------------------------------------------------
+
+    /// Creates a new plane bound to the root plane, owned by the pile.
+    pub fn new_plane(&mut self, y: i32, x: i32, rows: u32, cols: u32) -> NcResult<&mut NcPlane> {
+        let plane = NcPlane::new_bound(&mut self.root, y, x, rows, cols)?;
+        Ok(self.push(plane))
+    }
+
+    /// Creates a new plane bound to the root plane, owned by the pile,
+    /// with the provided `options`.
+    pub fn new_plane_with_options(&mut self, options: &NcPlaneOptions) -> NcResult<&mut NcPlane> {
+        let plane = NcPlane::with_options_bound(&mut self.root, options)?;
+        Ok(self.push(plane))
+    }
+
+    /// Moves `plane` to this pile, bound to the root plane, and takes
+    /// ownership of it.
+    ///
+    /// Any planes bound to `plane` stay in their current pile, reparented to
+    /// its previous parent.
+    pub fn adopt(&mut self, plane: NcPlaneOwned<'nc>) -> NcResult<&mut NcPlane> {
+        let raw = &*plane as *const NcPlane as *mut NcPlane;
+        nc_ptr("ncplane_reparent", unsafe {
+            crate::ncplane_reparent(raw, &mut *self.root)
+        })?;
+        Ok(self.push(plane))
+    }
+
+    /// Returns the `index`th plane owned by the pile, in the order they were
+    /// created or adopted, not counting the root plane.
+    pub fn plane(&self, index: usize) -> Option<&NcPlane> {
+        self.planes.get(index).map(|p| &**p)
+    }
+
+    /// Returns the `index`th plane owned by the pile, mutably.
+    ///
+    /// See [`plane`][NcPile#method.plane].
+    pub fn plane_mut(&mut self, index: usize) -> Option<&mut NcPlane> {
+        self.planes.get_mut(index).map(|p| &mut **p)
+    }
+
+    /// Returns the number of planes owned by the pile, not counting the root.
+    pub fn owned_len(&self) -> usize {
+        self.planes.len()
+    }
+
+    // Z-order -----------------------------------------------------------------
+
+    /// Returns the topmost plane of the pile.
+    pub fn top(&self) -> &NcPlane {
+        unsafe { &*crate::ncpile_top(self.root_ptr()) }
+    }
+
+    /// Returns the bottommost plane of the pile.
+    pub fn bottom(&self) -> &NcPlane {
+        unsafe { &*crate::ncpile_bottom(self.root_ptr()) }
+    }
+
+    /// Returns an iterator over the planes of the pile, in z-order,
+    /// from the top to the bottom.
+    pub fn planes(&self) -> NcPileIter<'_> {
+        unsafe { NcPileIter::new(self.top(), self.bottom()) }
+    }
+
+    /// Returns the plane of the pile identified by `id`, if any.
+    ///
+    /// The identifiers returned by the [`NcPlane`] lookup methods, like
+    /// [`above`][NcPlane#method.above], can be resolved with this.
+    pub fn plane_by_id(&self, id: NcPlaneId) -> Option<&NcPlane> {
+        self.planes().find(|p| p.id() == id)
+    }
+
+    /// Returns the plane of the pile identified by `id`, if any, mutably.
+    pub fn plane_by_id_mut(&mut self, id: NcPlaneId) -> Option<&mut NcPlane> {
+        self.plane_by_id(id)?;
+        Some(unsafe { &mut *id.0.as_ptr() })
+    }
+
+    /// Renders the pile.
+    ///
+    /// Rendering it again will blow away the render. To actually write out
+    /// the render, call [`rasterize`][NcPile#method.rasterize].
+    pub fn render(&mut self) -> NcResult<()> {
+        nc_result("ncpile_render", unsafe {
+            crate::ncpile_render(&mut *self.root)
+        })?;
+        Ok(())
+    }
+
+    /// Makes the physical screen match the last rendered frame of the pile.
+    ///
+    /// This is a blocking call, that waits for any other pile being
+    /// rasterized, and for [`Notcurses`] rendering the standard pile.
+    /// Don't call this before the pile has been rendered (doing so will
+    /// likely result in a blank screen).
+    pub fn rasterize(&mut self) -> NcResult<()> {
+        let _lock = crate::pile::raster_lock();
+        nc_result("ncpile_rasterize", unsafe {
+            crate::ncpile_rasterize(&mut *self.root)
+        })?;
+        Ok(())
+    }
+
+    /// Renders and rasterizes the pile in one shot.
+    pub fn show(&mut self) -> NcResult<()> {
+        self.render()?;
+        self.rasterize()
+    }
+
+    fn root_ptr(&self) -> *mut NcPlane {
+        &*self.root as *const NcPlane as *mut NcPlane
+    }
+
+    fn push(&mut self, plane: NcPlaneOwned<'nc>) -> &mut NcPlane {
+        self.planes.push(plane);
+        let last = self.planes.len() - 1;
+        &mut self.planes[last]
+    }
+
+    fn from_root(root: NcPlaneOwned<'nc>) -> Self {
+        Self {
+            planes: Vec::new(),
+            root,
+        }
+    }
+}
+
+impl<'nc> Deref for NcPile<'nc> {
+    type Target = NcPlane;
+
+    fn deref(&self) -> &NcPlane {
+        &self.root
+    }
+}
+
+impl<'nc> DerefMut for NcPile<'nc> {
+    fn deref_mut(&mut self) -> &mut NcPlane {
+        &mut self.root
+    }
+}
+
+impl<'a> NcPileIter<'a> {
+    /// New iterator from the `top` to the `bottom` planes of a pile.
+    ///
+    /// # Safety
+    ///
+    /// Both planes must belong to the same pile, and be valid for `'a`.
+    pub(crate) unsafe fn new(top: *const NcPlane, bottom: *const NcPlane) -> Self {
+        Self {
+            front: NonNull::new(top as *mut _),
+            back: NonNull::new(bottom as *mut _),
+            _pile: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for NcPileIter<'a> {
+    type Item = &'a NcPlane;
+
+    fn next(&mut self) -> Option<&'a NcPlane> {
+        let plane = self.front?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.front = NonNull::new(unsafe { crate::ncplane_below(plane.as_ptr()) });
+        }
+        Some(unsafe { &*plane.as_ptr() })
+    }
+}
+
+impl<'a> DoubleEndedIterator for NcPileIter<'a> {
+    fn next_back(&mut self) -> Option<&'a NcPlane> {
+        let plane = self.back?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.back = NonNull::new(unsafe { crate::ncplane_above(plane.as_ptr()) });
+        }
+        Some(unsafe { &*plane.as_ptr() })
+    }
+}
+
+impl<'a> FusedIterator for NcPileIter<'a> {}
@@ -0,0 +1,77 @@
+//! `NcPile`
+
+// the ncpile_* functions are listed in the `NcPlane` module.
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+use core::{marker::PhantomData, ptr::NonNull};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{NcPlane, NcPlaneOwned};
+
+// Serializes the writes to the terminal and the accesses to the stats of the
+// context, since rasterizing a pile updates both, and is not safe to do
+// concurrently with any other context-level call that touches them.
+static RASTER_LOCK: Mutex<()> = Mutex::new(());
+
+/// Locks the terminal output and the stats of the contexts.
+///
+/// Nothing is protected by the lock except the output itself, so a poisoned
+/// lock can be recovered.
+pub(crate) fn raster_lock() -> MutexGuard<'static, ()> {
+    RASTER_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A pile of [`NcPlane`]s, that owns its root plane, and the planes created
+/// in it or adopted by it.
+///
+/// A pile is a set of one or more planes, including the partial orderings
+/// made up of their binding and z-axis pointers. Each pile is rendered and
+/// rasterized independently of the others, so only the piles that changed
+/// need to be rendered, and they can be prepared off-screen.
+///
+/// It dereferences to its root plane, and all the planes it owns are
+/// destroyed when the pile is dropped.
+///
+/// # Threads
+///
+/// An `NcPile` is [`Send`], so that distinct piles can be operated on
+/// concurrently from different threads. Its planes can only be reached by
+/// borrowing the pile, so they always stay on the same thread as the pile.
+///
+/// Rendering doesn't touch the terminal, but rasterizing does, so
+/// [`rasterize`][NcPile#method.rasterize] is serialized against the safe
+/// methods that write to the terminal or read the stats, like the rendering
+/// methods of [`Notcurses`][crate::Notcurses], the cursor and mouse guards,
+/// and the [`NcStatsOwned`][crate::NcStatsOwned] snapshots. The raw C
+/// functions that do so must not be called while another thread rasterizes.
+///
+/// See the [`NcPlane`] documentation for more details about piles.
+#[derive(Debug)]
+pub struct NcPile<'nc> {
+    // the owned planes are destroyed before the root, in creation order.
+    pub(crate) planes: Vec<NcPlaneOwned<'nc>>,
+    pub(crate) root: NcPlaneOwned<'nc>,
+}
+
+// SAFETY: the planes of the pile are only reachable through the pile, which
+// is not `Sync`, and the context is thread safe for operations on distinct
+// piles. Rasterizing writes to the terminal and updates the stats, so it's
+// serialized by `raster_lock` against every safe context-level call that also
+// does, i.e. rendering, the cursor and mouse guards, and the stats snapshots.
+// The raw C functions must not be called concurrently with a rasterization.
+unsafe impl<'nc> Send for NcPile<'nc> {}
+
+/// An iterator over the planes of an [`NcPile`], in z-order.
+///
+/// Created by [`NcPile::planes`]. It iterates from the top to the bottom
+/// plane, or in reverse.
+#[derive(Debug)]
+pub struct NcPileIter<'a> {
+    pub(crate) front: Option<NonNull<NcPlane>>,
+    pub(crate) back: Option<NonNull<NcPlane>>,
+    pub(crate) _pile: PhantomData<&'a NcPlane>,
+}
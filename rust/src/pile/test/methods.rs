@@ -0,0 +1,77 @@
+//! Test `NcPile` methods and associated functions.
+
+use serial_test::serial;
+
+use crate::{FullMode, NcPile, NcPlane};
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn ncpile_is_send() {
+    assert_send::<NcPile<'static>>();
+}
+
+#[test]
+#[serial]
+fn ncpile_planes() {
+    let nc = FullMode::new().unwrap();
+    let mut pile = NcPile::new(&nc, 0, 0, 10, 10).unwrap();
+    assert_eq![1, pile.planes().count()];
+
+    let lower = pile.new_plane(1, 1, 2, 2).unwrap() as *const NcPlane;
+    let upper = pile.new_plane(2, 2, 2, 2).unwrap() as *const NcPlane;
+    let root = pile.root() as *const NcPlane;
+    assert_eq![2, pile.owned_len()];
+    assert_eq![Some(lower), pile.plane(0).map(|p| p as *const _)];
+
+    let planes: Vec<*const NcPlane> = pile.planes().map(|p| p as *const _).collect();
+    assert_eq![vec![upper, lower, root], planes];
+    assert_eq![
+        planes.iter().rev().copied().collect::<Vec<_>>(),
+        pile.planes()
+            .rev()
+            .map(|p| p as *const _)
+            .collect::<Vec<_>>()
+    ];
+    assert_eq![upper, pile.top() as *const _];
+    assert_eq![root, pile.bottom() as *const _];
+
+    let lower_id = pile.plane(0).unwrap().id();
+    assert_eq![Some(lower_id), pile.top().below()];
+    assert_eq![
+        Some(lower),
+        pile.plane_by_id(lower_id).map(|p| p as *const _)
+    ];
+    pile.plane_by_id_mut(lower_id).unwrap().move_top();
+    assert_eq![lower, pile.top() as *const _];
+    let outside = NcPlane::new(&nc, 0, 0, 1, 1).unwrap();
+    assert![pile.plane_by_id(outside.id()).is_none()];
+
+    pile.render().unwrap();
+    pile.rasterize().unwrap();
+}
+
+#[test]
+#[serial]
+fn ncpile_threads() {
+    let nc = FullMode::new().unwrap();
+    let mut piles = vec![
+        NcPile::new(&nc, 0, 0, 4, 4).unwrap(),
+        NcPile::new(&nc, 0, 0, 4, 4).unwrap(),
+    ];
+    let mut adopted = NcPile::new(&nc, 0, 0, 1, 1).unwrap().into_root();
+    adopted.putstr("x").unwrap();
+    piles[0].adopt(adopted).unwrap();
+
+    std::thread::scope(|s| {
+        for pile in piles.iter_mut() {
+            s.spawn(move || {
+                pile.new_plane(1, 1, 2, 2).unwrap().putstr("hi").unwrap();
+                pile.render().unwrap();
+                pile.rasterize().unwrap();
+            });
+        }
+        nc.render().unwrap();
+    });
+    assert_eq![2, piles[0].owned_len()];
+}
@@ -0,0 +1,4 @@
+//! `NcPile` tests
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,98 @@
+//! Rust data attached to an `NcPlane` through its user pointer.
+
+use core::{any::Any, ptr::null_mut};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{NcIntResult, NcPlane, NcResult, NCRESULT_ERR, NCRESULT_OK};
+
+/// A boxed resize callback.
+pub(crate) type NcResizeCbBox = Box<dyn FnMut(&mut NcPlane) -> NcResult<()> + Send>;
+
+/// The Rust data owned by a plane, stored boxed in its user pointer.
+///
+/// It's freed by [`free`], which must be called before destroying the plane.
+#[derive(Default)]
+pub(crate) struct NcPlaneExtra {
+    pub(crate) resizecb: Option<NcResizeCbBox>,
+
+    // incremented each time the resize callback is replaced, so that a
+    // callback which replaces itself is not restored after running.
+    pub(crate) resizecb_gen: u32,
+
+    pub(crate) data: Option<Box<dyn Any + Send>>,
+}
+
+impl NcPlaneExtra {
+    /// Returns the extra data of `plane`, if any.
+    ///
+    /// # Safety
+    ///
+    /// The user pointer of the plane must be either null or set by this module,
+    /// and the returned pointer must not outlive the plane.
+    pub(crate) unsafe fn get(plane: *mut NcPlane) -> *mut NcPlaneExtra {
+        crate::ncplane_userptr(plane) as *mut NcPlaneExtra
+    }
+
+    /// Returns the extra data of `plane`, creating it if needed.
+    ///
+    /// # Safety
+    ///
+    /// See [`get`][NcPlaneExtra::get].
+    pub(crate) unsafe fn get_or_insert(plane: *mut NcPlane) -> &'static mut NcPlaneExtra {
+        let mut extra = Self::get(plane);
+        if extra.is_null() {
+            extra = Box::into_raw(Box::new(NcPlaneExtra::default()));
+            crate::ncplane_set_userptr(plane, extra as *mut _);
+        }
+        &mut *extra
+    }
+
+    /// Frees the extra data of `plane`, if any.
+    ///
+    /// # Safety
+    ///
+    /// See [`get`][NcPlaneExtra::get]. Any reference returned by
+    /// [`get_or_insert`][NcPlaneExtra::get_or_insert] must be dead.
+    pub(crate) unsafe fn free(plane: *mut NcPlane) {
+        let extra = Self::get(plane);
+        if !extra.is_null() {
+            crate::ncplane_set_userptr(plane, null_mut());
+            drop(Box::from_raw(extra));
+        }
+    }
+}
+
+/// The C resize callback that calls the boxed Rust resize callback.
+///
+/// A panic in the callback is caught and reported as an error.
+pub(crate) unsafe extern "C" fn resizecb_trampoline(plane: *mut NcPlane) -> NcIntResult {
+    let extra = NcPlaneExtra::get(plane);
+    if extra.is_null() {
+        return NCRESULT_OK;
+    }
+    let gen = (*extra).resizecb_gen;
+    let mut cb = match (*extra).resizecb.take() {
+        Some(cb) => cb,
+        None => return NCRESULT_OK,
+    };
+
+    let res = catch_unwind(AssertUnwindSafe(|| cb(&mut *plane)));
+
+    // the callback could have replaced itself, or freed the extra data
+    let extra = NcPlaneExtra::get(plane);
+    if !extra.is_null() && (*extra).resizecb_gen == gen {
+        (*extra).resizecb = Some(cb);
+    }
+    match res {
+        Ok(Ok(())) => NCRESULT_OK,
+        _ => NCRESULT_ERR,
+    }
+}
+
+/// The C resize callback that calls [`NcPlane::resize_maximize`].
+pub(crate) unsafe extern "C" fn resize_maximize_trampoline(plane: *mut NcPlane) -> NcIntResult {
+    match (*plane).resize_maximize() {
+        Ok(()) => NCRESULT_OK,
+        Err(_) => NCRESULT_ERR,
+    }
+}
@@ -1,8 +1,35 @@
 //! `NcPlane*` methods and associated functions.
 
-use core::ptr::{null, null_mut};
+use core::{
+    any::Any,
+    fmt,
+    ptr::{null, null_mut, NonNull},
+};
+use std::ffi::CString;
 
-use crate::{cstring, NcAlign, NcCell, NcPlane, NcPlaneOptions, NcResult, Notcurses};
+use crate::{
+    error::{nc_cstring, nc_ptr, nc_result},
+    ncplane_resize_simple,
+    plane::{
+        extra::{resize_maximize_trampoline, resizecb_trampoline, NcPlaneExtra, NcResizeCbBox},
+        text,
+    },
+    NcAlign, NcCell, NcChannelPair, NcError, NcIntResult, NcPlane, NcPlaneHandle, NcPlaneId,
+    NcPlaneOptions, NcPlaneOwned, NcRect, NcResult, Notcurses, NCRESULT_ERR, NCRESULT_OK,
+};
+
+/// # `NcRect` Methods
+impl NcRect {
+    /// New `NcRect`, with its upper-left corner at `y`, `x`.
+    pub const fn new(y: i32, x: i32, rows: u32, cols: u32) -> Self {
+        Self { y, x, rows, cols }
+    }
+
+    /// Returns true if the region has no cells.
+    pub const fn is_empty(&self) -> bool {
+        self.rows == 0 || self.cols == 0
+    }
+}
 
 /// # `NcPlaneOptions` Constructors
 impl NcPlaneOptions {
@@ -41,51 +68,82 @@ impl NcPlaneOptions {
 }
 
 /// # `NcPlane` Constructors
+///
+/// They return an owned plane, which is destroyed when dropped.
+#[allow(clippy::new_ret_no_self)]
 impl NcPlane {
     /// New NcPlane.
     ///
     /// The returned plane will be the top, bottom, and root of this new pile.
-    pub fn new<'a>(nc: &mut Notcurses, y: i32, x: i32, rows: u32, cols: u32) -> &'a mut NcPlane {
+    pub fn new<'nc>(
+        nc: &'nc Notcurses,
+        y: i32,
+        x: i32,
+        rows: u32,
+        cols: u32,
+    ) -> NcResult<NcPlaneOwned<'nc>> {
         let options = NcPlaneOptions::new(y, x, rows, cols);
-        unsafe { &mut *crate::ncpile_create(nc, &options) }
+        Self::with_options(nc, &options)
     }
 
     /// New NcPlane, expects an [NcPlaneOptions] struct.
     ///
     /// The returned plane will be the top, bottom, and root of this new pile.
-    pub fn with_options<'a>(nc: &mut Notcurses, options: &NcPlaneOptions) -> &'a mut NcPlane {
-        unsafe { &mut *crate::ncpile_create(nc, options) }
+    ///
+    /// The `userptr` of the options is ignored, since the user pointer is
+    /// reserved for the Rust data of the plane. See
+    /// [`set_user_data`][NcPlane#method.set_user_data].
+    pub fn with_options<'nc>(
+        nc: &'nc Notcurses,
+        options: &NcPlaneOptions,
+    ) -> NcResult<NcPlaneOwned<'nc>> {
+        let options = NcPlaneOptions {
+            userptr: null_mut(),
+            ..*options
+        };
+        let plane = nc_ptr("ncpile_create", unsafe {
+            crate::ncpile_create(nc as *const _ as *mut _, &options)
+        })?;
+        Ok(unsafe { NcPlaneOwned::from_ptr(plane) })
     }
 
     /// New NcPlane, bound to another NcPlane.
-    pub fn new_bound<'a>(
-        bound_to: &mut NcPlane,
+    pub fn new_bound<'nc>(
+        bound_to: &mut impl NcPlaneHandle<'nc>,
         y: i32,
         x: i32,
         rows: u32,
         cols: u32,
-    ) -> &'a mut NcPlane {
+    ) -> NcResult<NcPlaneOwned<'nc>> {
         let options = NcPlaneOptions::new(y, x, rows, cols);
-        unsafe { &mut *crate::ncplane_create(bound_to, &options) }
+        Self::with_options_bound(bound_to, &options)
     }
 
     /// New NcPlane, bound to another plane, expects an [NcPlaneOptions] struct.
     ///
-    /// The returned plane will be the top, bottom, and root of this new pile.
-    pub fn with_options_bound<'a>(nc: &mut Notcurses, options: &NcPlaneOptions) -> &'a mut NcPlane {
-        unsafe { &mut *crate::ncpile_create(nc, options) }
+    /// The `userptr` of the options is ignored, like in
+    /// [`with_options`][NcPlane#method.with_options].
+    pub fn with_options_bound<'nc>(
+        bound_to: &mut impl NcPlaneHandle<'nc>,
+        options: &NcPlaneOptions,
+    ) -> NcResult<NcPlaneOwned<'nc>> {
+        let options = NcPlaneOptions {
+            userptr: null_mut(),
+            ..*options
+        };
+        let plane = nc_ptr("ncplane_create", unsafe {
+            crate::ncplane_create(&mut **bound_to, &options)
+        })?;
+        Ok(unsafe { NcPlaneOwned::from_ptr(plane) })
     }
 
     /// New NcPlane, with the same dimensions of the terminal.
     ///
     /// The returned plane will be the top, bottom, and root of this new pile.
-    pub fn new_termsize<'a>(nc: &mut Notcurses) -> &'a mut NcPlane {
+    pub fn new_termsize<'nc>(nc: &'nc Notcurses) -> NcResult<NcPlaneOwned<'nc>> {
         let (mut trows, mut tcols) = (0, 0);
         crate::notcurses_term_dim_yx(nc, &mut trows, &mut tcols);
-        assert![(trows > 0) & (tcols > 0)];
-        unsafe {
-            &mut *crate::ncpile_create(nc, &NcPlaneOptions::new(0, 0, trows as u32, tcols as u32))
-        }
+        Self::with_options(nc, &NcPlaneOptions::new(0, 0, trows, tcols))
     }
 }
 
@@ -152,6 +210,146 @@ impl NcPlane {
 
     // TODO: resize
 
+    /// Realigns this [NcPlane] against its parent, using the alignment
+    /// specified at creation time.
+    ///
+    /// Suitable for use as a resize callback, see
+    /// [`set_resize_realign`][NcPlane#method.set_resize_realign].
+    pub fn resize_realign(&mut self) -> NcResult<()> {
+        nc_result("ncplane_resize_realign", unsafe {
+            crate::ncplane_resize_realign(self)
+        })?;
+        Ok(())
+    }
+
+    /// Resizes this [NcPlane] to the size of its parent, keeping its origin.
+    ///
+    /// A root plane is resized to the size of the terminal.
+    ///
+    /// Suitable for use as a resize callback, see
+    /// [`set_resize_maximize`][NcPlane#method.set_resize_maximize].
+    //
+    // Unlike ncplane_resize_maximize, which uses the size of the pile, and that
+    // is only known after the pile has been rendered.
+    pub fn resize_maximize(&mut self) -> NcResult<()> {
+        let (mut rows, mut cols) = (0, 0);
+        unsafe {
+            let mut parent = crate::ncplane_parent_const(self);
+            if parent.is_null() || core::ptr::eq(parent, self) {
+                parent = crate::notcurses_stdplane_const(crate::ncplane_notcurses_const(self));
+            }
+            crate::ncplane_dim_yx(parent, &mut rows, &mut cols);
+        }
+        nc_result("ncplane_resize", ncplane_resize_simple(self, rows, cols))?;
+        Ok(())
+    }
+
+    /// Sets a closure to be called after the parent of this [NcPlane] has
+    /// been resized, replacing any previous resize callback.
+    ///
+    /// The closure is owned by the plane, and dropped when the plane is
+    /// destroyed through its [NcPlaneOwned] handle, or when it's replaced.
+    /// Returning an error, or panicking, makes the resize of the parent fail.
+    ///
+    /// It's stored in the user pointer of the plane, which is reserved for the
+    /// Rust data of the plane, see [`set_user_data`][NcPlane#method.set_user_data].
+    pub fn set_resize_cb<F>(&mut self, cb: F)
+    where
+        F: FnMut(&mut NcPlane) -> NcResult<()> + Send + 'static,
+    {
+        self.replace_resize_cb(Some(Box::new(cb)));
+        unsafe { crate::ncplane_set_resizecb(self, Some(resizecb_trampoline)) }
+    }
+
+    /// Sets [`resize_realign`][NcPlane#method.resize_realign] as the resize
+    /// callback, replacing any previous one.
+    pub fn set_resize_realign(&mut self) {
+        self.replace_resize_cb(None);
+        unsafe { crate::ncplane_set_resizecb(self, Some(crate::ncplane_resize_realign)) }
+    }
+
+    /// Sets [`resize_maximize`][NcPlane#method.resize_maximize] as the resize
+    /// callback, replacing any previous one.
+    pub fn set_resize_maximize(&mut self) {
+        self.replace_resize_cb(None);
+        unsafe { crate::ncplane_set_resizecb(self, Some(resize_maximize_trampoline)) }
+    }
+
+    /// Removes the resize callback, if any.
+    pub fn unset_resize_cb(&mut self) {
+        self.replace_resize_cb(None);
+        unsafe { crate::ncplane_set_resizecb(self, None) }
+    }
+
+    /// Returns true if this [NcPlane] has a resize callback.
+    pub fn has_resize_cb(&self) -> bool {
+        unsafe { crate::ncplane_resizecb(self).is_some() }
+    }
+
+    fn replace_resize_cb(&mut self, cb: Option<NcResizeCbBox>) {
+        let extra = unsafe { NcPlaneExtra::get(self) };
+        if cb.is_none() && extra.is_null() {
+            return;
+        }
+        let extra = unsafe { NcPlaneExtra::get_or_insert(self) };
+        extra.resizecb = cb;
+        extra.resizecb_gen = extra.resizecb_gen.wrapping_add(1);
+    }
+
+    // User data ---------------------------------------------------------------
+
+    /// Attaches `data` to this [NcPlane], dropping any previous data.
+    ///
+    /// The data is owned by the plane, and dropped when the plane is
+    /// destroyed through its [NcPlaneOwned] handle, or when it's replaced.
+    ///
+    /// It's stored in the user pointer of the plane, which is reserved for it:
+    /// the safe constructors ignore the `userptr` of [NcPlaneOptions], so it
+    /// can only be set otherwise through the unsafe [ncplane_set_userptr]
+    /// function, whose callers must keep it null for planes managed by this crate.
+    ///
+    /// [ncplane_set_userptr]: crate::ncplane_set_userptr
+    pub fn set_user_data<T: Any + Send>(&mut self, data: T) {
+        unsafe { NcPlaneExtra::get_or_insert(self) }.data = Some(Box::new(data));
+    }
+
+    /// Returns a reference to the data attached to this [NcPlane], if there's
+    /// any, and it's of type `T`.
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        let extra = unsafe { NcPlaneExtra::get(self as *const _ as *mut _).as_ref() }?;
+        extra.data.as_ref()?.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the data attached to this [NcPlane],
+    /// if there's any, and it's of type `T`.
+    pub fn user_data_mut<T: Any>(&mut self) -> Option<&mut T> {
+        let extra = unsafe { NcPlaneExtra::get(self).as_mut() }?;
+        extra.data.as_mut()?.downcast_mut()
+    }
+
+    /// Returns true if there's data attached to this [NcPlane], of any type.
+    pub fn has_user_data(&self) -> bool {
+        let extra = unsafe { NcPlaneExtra::get(self as *const _ as *mut _).as_ref() };
+        extra.and_then(|e| e.data.as_ref()).is_some()
+    }
+
+    /// Detaches and returns the data attached to this [NcPlane], if there's
+    /// any, and it's of type `T`. Otherwise the data is left attached.
+    pub fn take_user_data<T: Any>(&mut self) -> Option<T> {
+        let extra = unsafe { NcPlaneExtra::get(self).as_mut() }?;
+        if !extra.data.as_ref()?.is::<T>() {
+            return None;
+        }
+        extra.data.take()?.downcast().ok().map(|b| *b)
+    }
+
+    /// Drops the data attached to this [NcPlane], if any.
+    pub fn clear_user_data(&mut self) {
+        if let Some(extra) = unsafe { NcPlaneExtra::get(self).as_mut() } {
+            extra.data = None;
+        }
+    }
+
     // Write -------------------------------------------------------------------
 
     /// Erases every NcCell in the NcPlane, resetting all attributes to normal,
@@ -168,9 +366,11 @@ impl NcPlane {
     ///
     /// The new NcCell must already be associated with the Plane.
     /// On success, returns the number of columns the cursor was advanced.
-    /// On failure, -1 is returned.
-    pub fn putc_yx(&mut self, y: i32, x: i32, cell: &NcCell) -> NcResult {
-        unsafe { crate::ncplane_putc_yx(self, y, x, cell) }
+    pub fn putc_yx(&mut self, y: i32, x: i32, cell: &NcCell) -> NcResult<u32> {
+        let res = nc_result("ncplane_putc_yx", unsafe {
+            crate::ncplane_putc_yx(self, y, x, cell)
+        })?;
+        Ok(res as u32)
     }
 
     /// Replaces the NcCell at the current coordinates with the provided NcCell,
@@ -178,18 +378,19 @@ impl NcPlane {
     ///
     /// The new NcCell must already be associated with the Plane.
     /// On success, returns the number of columns the cursor was advanced.
-    /// On failure, -1 is returned.
-    pub fn putc(&mut self, cell: &NcCell) -> NcResult {
-        crate::ncplane_putc(self, cell)
+    pub fn putc(&mut self, cell: &NcCell) -> NcResult<u32> {
+        let res = nc_result("ncplane_putc", crate::ncplane_putc(self, cell))?;
+        Ok(res as u32)
     }
 
     /// Writes a series of [NcEgc]s to the current location, using the current style.
-    pub fn putstr(&mut self, string: &str) -> NcResult {
-        crate::ncplane_putstr(self, string)
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    pub fn putstr(&mut self, string: &str) -> NcResult<u32> {
+        let res = nc_result("ncplane_putstr", crate::ncplane_putstr(self, string))?;
+        Ok(res as u32)
     }
 
-    // TODO: Stained Replace a string's worth of glyphs at the current cursor location, but retain the styling. The current styling of the plane will not be changed
-
     /// Write a string, which is a series of [NcEgc]s, to the current location,
     /// using the current style.
     ///
@@ -199,21 +400,434 @@ impl NcPlane {
     /// Advances the cursor by some positive number of columns (though not
     /// beyond the end of the plane); this number is returned on success.
     ///
-    /// On error, a non-positive number is returned, indicating the number of
-    /// columns which were written before the error.
-    pub fn putstr_yx(&mut self, y: i32, x: i32, string: &str) -> NcResult {
-        unsafe { crate::ncplane_putstr_yx(self, y, x, cstring![string]) }
+    /// On error, the [NcError][crate::NcError] code is the negated number of
+    /// columns which were written before the error. If the error happens
+    /// before any column was written, the C function returns 0, so `Ok(0)`
+    /// is returned instead.
+    pub fn putstr_yx(&mut self, y: i32, x: i32, string: &str) -> NcResult<u32> {
+        let cs = nc_cstring("ncplane_putstr_yx", string)?;
+        let res = nc_result("ncplane_putstr_yx", unsafe {
+            crate::ncplane_putstr_yx(self, y, x, cs.as_ptr())
+        })?;
+        Ok(res as u32)
+    }
+
+    /// Writes a string, which is a series of [NcEgc]s, to the row `y`,
+    /// aligned according to `align`, using the current style.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    pub fn putstr_aligned(&mut self, y: i32, align: NcAlign, string: &str) -> NcResult<u32> {
+        let cs = nc_cstring("ncplane_putstr_aligned", string)?;
+        let res = nc_result("ncplane_putstr_aligned", unsafe {
+            crate::ncplane_putstr_aligned(self, y, align, cs.as_ptr())
+        })?;
+        Ok(res as u32)
+    }
+
+    // Stained write -----------------------------------------------------------
+
+    /// Replaces a string's worth of glyphs at the current location, but
+    /// retains the styling of each cell. The current style of the plane
+    /// is not changed.
+    ///
+    /// On success, returns the number of bytes of `string` that were written.
+    ///
+    /// On error, the [NcError][crate::NcError] code is the negated number of
+    /// bytes which were written before the error.
+    //
+    // ncplane_putstr_stained is not called, because it doesn't check that the
+    // cursor is inside the plane before reading the cell under it.
+    pub fn putstr_stained(&mut self, string: &str) -> NcResult<u32> {
+        nc_cstring("ncplane_putstr_stained", string)?;
+        let mut written = 0;
+        while let Some(rest) = string.get(written..).filter(|rest| !rest.is_empty()) {
+            match self.putegc_stained(rest) {
+                Ok((_, 0)) => break,
+                Ok((_, bytes)) => written += bytes,
+                Err(_) => {
+                    return Err(NcError::Code {
+                        func: "ncplane_putstr_stained",
+                        code: -(written as NcIntResult),
+                    })
+                }
+            }
+        }
+        Ok(written as u32)
+    }
+
+    /// Replaces the glyph at the current location with `ch`, but retains the
+    /// styling of the cell. The current style of the plane is not changed.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    pub fn putchar_stained(&mut self, ch: char) -> NcResult<u32> {
+        self.check_cursor("ncplane_putchar_stained")?;
+        if ch.is_ascii() {
+            let res = nc_result("ncplane_putchar_stained", unsafe {
+                crate::ncplane_putchar_stained(self, ch as u8 as _)
+            })?;
+            Ok(res as u32)
+        } else {
+            let (cols, _) = self.putegc_stained(ch.encode_utf8(&mut [0; 4]))?;
+            Ok(cols)
+        }
+    }
+
+    /// Replaces the glyph at the current location with the first extended
+    /// grapheme cluster of `egc`, but retains the styling of the cell.
+    /// The current style of the plane is not changed.
+    ///
+    /// On success, returns the number of columns the cursor was advanced,
+    /// and the number of bytes of `egc` that were consumed.
+    pub fn putegc_stained(&mut self, egc: &str) -> NcResult<(u32, usize)> {
+        let cs = nc_cstring("ncplane_putegc_stained", egc)?;
+        self.check_cursor("ncplane_putegc_stained")?;
+        let mut bytes = 0;
+        let res = nc_result("ncplane_putegc_stained", unsafe {
+            crate::ncplane_putegc_stained(self, cs.as_ptr(), &mut bytes)
+        })?;
+        Ok((res as u32, bytes as usize))
+    }
+
+    // The stained C functions read the cell under the cursor without checking
+    // that it's inside the plane, and it's left past the last column by writes.
+    fn check_cursor(&self, func: &'static str) -> NcResult<()> {
+        let (y, x) = self.cursor_yx();
+        let (rows, cols) = self.dim_yx();
+        if y < 0 || x < 0 || y as u32 >= rows || x as u32 >= cols {
+            return Err(NcError::Code {
+                func,
+                code: NCRESULT_ERR,
+            });
+        }
+        Ok(())
+    }
+
+    /// Applies a gradient of channels to the cells in `rect`, without
+    /// changing their glyphs nor styles.
+    ///
+    /// The channels of each cell are interpolated between the ones of the
+    /// upper-left, upper-right, lower-left and lower-right corners.
+    /// The cursor is left where it was.
+    ///
+    /// On success, returns the number of cells that were stained.
+    pub fn stain(
+        &mut self,
+        rect: NcRect,
+        ul: NcChannelPair,
+        ur: NcChannelPair,
+        ll: NcChannelPair,
+        lr: NcChannelPair,
+    ) -> NcResult<u32> {
+        if rect.is_empty() {
+            return Ok(0);
+        }
+        let (y, x) = self.cursor_yx();
+        nc_result("ncplane_cursor_move_yx", unsafe {
+            crate::ncplane_cursor_move_yx(self, rect.y, rect.x)
+        })?;
+        let res = unsafe {
+            crate::ncplane_stain(
+                self,
+                rect.y + rect.rows as i32 - 1,
+                rect.x + rect.cols as i32 - 1,
+                ul,
+                ur,
+                ll,
+                lr,
+            )
+        };
+        unsafe { crate::ncplane_cursor_move_yx(self, y, x) };
+        Ok(nc_result("ncplane_stain", res)? as u32)
+    }
+
+    /// Applies the same `channels` to the cells in `rect`, without changing
+    /// their glyphs nor styles.
+    ///
+    /// See [`stain`][NcPlane#method.stain].
+    pub fn stain_uniform(&mut self, rect: NcRect, channels: NcChannelPair) -> NcResult<u32> {
+        self.stain(rect, channels, channels, channels, channels)
+    }
+
+    // Formatted write ---------------------------------------------------------
+
+    /// Writes the formatted `args` to the current location, using the
+    /// current style.
+    ///
+    /// It's the Rust equivalent of `ncplane_printf()`, see also the
+    /// [`ncplane_printf!`][crate::ncplane_printf!] macro.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    /// It's an error if nothing could be written.
+    pub fn printf(&mut self, args: fmt::Arguments) -> NcResult<u32> {
+        self.printf_yx(-1, -1, args)
+    }
+
+    /// Writes the formatted `args` to the location `y`, `x`, using the
+    /// current style. A coordinate of -1 keeps the current cursor position
+    /// on that axis.
+    ///
+    /// It's the Rust equivalent of `ncplane_printf_yx()`, see also the
+    /// [`ncplane_printf_yx!`][crate::ncplane_printf_yx!] macro.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    /// It's an error if nothing could be written.
+    pub fn printf_yx(&mut self, y: i32, x: i32, args: fmt::Arguments) -> NcResult<u32> {
+        let string = args.to_string();
+        let cs = nc_cstring("ncplane_putstr_yx", &string)?;
+        let res = unsafe { crate::ncplane_putstr_yx(self, y, x, cs.as_ptr()) };
+        putstr_result("ncplane_putstr_yx", &string, res)
+    }
+
+    /// Writes the formatted `args` to the row `y`, aligned according to
+    /// `align`, using the current style.
+    ///
+    /// It's the Rust equivalent of `ncplane_printf_aligned()`, see also the
+    /// [`ncplane_printf_aligned!`][crate::ncplane_printf_aligned!] macro.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    /// It's an error if nothing could be written.
+    pub fn printf_aligned(
+        &mut self,
+        y: i32,
+        align: NcAlign,
+        args: fmt::Arguments,
+    ) -> NcResult<u32> {
+        let string = args.to_string();
+        let cs = nc_cstring("ncplane_putstr_aligned", &string)?;
+        let res = unsafe { crate::ncplane_putstr_aligned(self, y, align, cs.as_ptr()) };
+        putstr_result("ncplane_putstr_aligned", &string, res)
+    }
+
+    /// Writes the formatted `args` to the current location, replacing the
+    /// glyphs but retaining the styling of each cell.
+    ///
+    /// It's the Rust equivalent of `ncplane_printf_stained()`, see also the
+    /// [`ncplane_printf_stained!`][crate::ncplane_printf_stained!] macro.
+    ///
+    /// On success, returns the number of bytes that were written.
+    /// It's an error if nothing could be written.
+    pub fn printf_stained(&mut self, args: fmt::Arguments) -> NcResult<u32> {
+        self.putstr_stained(&args.to_string())
+    }
+
+    // Text --------------------------------------------------------------------
+
+    /// Writes `text` starting at the row `y`, aligned according to `align`,
+    /// and broken into lines at whitespace, or wherever a word reaches the
+    /// edge of the plane.
+    ///
+    /// Returns the number of bytes of `text` that were consumed, which is
+    /// less than its length if the plane ran out of room. Any unconsumed text
+    /// can then be written into another plane, or after clearing this one.
+    pub fn puttext(&mut self, y: i32, align: NcAlign, text: &str) -> NcResult<usize> {
+        let cs = nc_cstring("ncplane_puttext", text)?;
+        let mut bytes = 0;
+        let res = unsafe { crate::ncplane_puttext(self, y, align, cs.as_ptr(), &mut bytes) };
+        let bytes = bytes as usize;
+        // it fails when it can't consume the whole text
+        if res < NCRESULT_OK && (bytes == 0 || bytes >= text.len()) {
+            nc_result("ncplane_puttext", res)?;
+        }
+        Ok(bytes.min(text.len()))
+    }
+
+    /// Lays out `text` as a paragraph in the region `rect`, aligned according
+    /// to `align`, using the current style.
+    ///
+    /// Runs of whitespace are collapsed, and newlines start a new line.
+    /// Lines are broken at whitespace, using the widths reported by
+    /// `ncstrwidth()`, and words wider than the region are broken wherever
+    /// they reach its edge, without adding a hyphen.
+    ///
+    /// If the text doesn't fit, and `ellipsis` is true, the last line is
+    /// truncated to end with an ellipsis (`…`).
+    ///
+    /// Returns the number of bytes of `text` that were consumed, so that
+    /// long texts can be paginated.
+    pub fn put_paragraph(
+        &mut self,
+        rect: NcRect,
+        align: NcAlign,
+        text: &str,
+        ellipsis: bool,
+    ) -> NcResult<usize> {
+        let (lines, consumed) =
+            text::layout(text, rect.rows, rect.cols, ellipsis, text::ncstrwidth);
+        for (row, line) in lines.iter().enumerate() {
+            let free = rect.cols.saturating_sub(line.width) as i32;
+            let x = match align {
+                crate::NCALIGN_CENTER => rect.x + free / 2,
+                crate::NCALIGN_RIGHT => rect.x + free,
+                _ => rect.x,
+            };
+            self.putstr_yx(rect.y + row as i32, x, &line.text)?;
+        }
+        Ok(consumed)
     }
 
     // Pile --------------------------------------------------------------------
 
-    /// Returns the bottommost [NcPlane] of the pile that contains this [NnPlane].
-    pub fn bottom<'a>(&mut self) -> &'a mut NcPlane {
-        unsafe { &mut *crate::ncpile_bottom(self) }
+    /// Returns the identifier of this [NcPlane].
+    pub fn id(&self) -> NcPlaneId {
+        NcPlaneId(NonNull::from(self))
+    }
+
+    /// Returns the bottommost [NcPlane] of the pile that contains this [NcPlane].
+    ///
+    /// It can be owned by another handle, so only its identifier is returned.
+    pub fn bottom(&self) -> NcPlaneId {
+        NcPlaneId::from_ptr(unsafe { crate::ncpile_bottom(self.as_mut_ptr()) })
+            .expect("a pile always has a bottom plane")
+    }
+
+    /// Returns the topmost [NcPlane] of the pile that contains this [NcPlane].
+    ///
+    /// It can be owned by another handle, so only its identifier is returned.
+    pub fn top(&self) -> NcPlaneId {
+        NcPlaneId::from_ptr(unsafe { crate::ncpile_top(self.as_mut_ptr()) })
+            .expect("a pile always has a top plane")
+    }
+
+    /// Returns the identifiers of the planes of the pile that contains this
+    /// [NcPlane], in z-order, from the top to the bottom.
+    ///
+    /// They can be owned by other handles, so only their identifiers are
+    /// returned. See [`NcPile::planes`][crate::NcPile#method.planes] for
+    /// iterating over the planes of an [`NcPile`][crate::NcPile].
+    pub fn pile_planes(&self) -> Vec<NcPlaneId> {
+        let mut planes = Vec::new();
+        let mut plane = unsafe { crate::ncpile_top(self.as_mut_ptr()) };
+        while let Some(id) = NcPlaneId::from_ptr(plane) {
+            planes.push(id);
+            plane = unsafe { crate::ncplane_below(plane) };
+        }
+        planes
+    }
+
+    /// Returns the identifier of the [NcPlane] immediately above this one,
+    /// if any.
+    pub fn above(&self) -> Option<NcPlaneId> {
+        NcPlaneId::from_ptr(unsafe { crate::ncplane_above(self.as_mut_ptr()) })
     }
 
-    /// Returns the topmost [NcPlane] of the pile that contains this [NnPlane].
-    pub fn top<'a>(&mut self) -> &'a mut NcPlane {
-        unsafe { &mut *crate::ncpile_top(self) }
+    /// Returns the identifier of the [NcPlane] immediately below this one,
+    /// if any.
+    pub fn below(&self) -> Option<NcPlaneId> {
+        NcPlaneId::from_ptr(unsafe { crate::ncplane_below(self.as_mut_ptr()) })
+    }
+
+    /// Relocates this [NcPlane] at the top of the z-buffer of its pile.
+    pub fn move_top(&mut self) {
+        unsafe { crate::ncplane_move_top(self) }
+    }
+
+    /// Relocates this [NcPlane] at the bottom of the z-buffer of its pile.
+    pub fn move_bottom(&mut self) {
+        unsafe { crate::ncplane_move_bottom(self) }
+    }
+
+    /// Splices this [NcPlane] out of the z-buffer, and reinserts it just
+    /// above `above`.
+    ///
+    /// Fails if both planes belong to different piles.
+    pub fn move_above(&mut self, above: &mut NcPlane) -> NcResult<()> {
+        self.check_same_pile("ncplane_move_above", above)?;
+        nc_result("ncplane_move_above", unsafe {
+            crate::ncplane_move_above(self, above)
+        })?;
+        Ok(())
+    }
+
+    /// Splices this [NcPlane] out of the z-buffer, and reinserts it just
+    /// below `below`.
+    ///
+    /// Fails if both planes belong to different piles.
+    pub fn move_below(&mut self, below: &mut NcPlane) -> NcResult<()> {
+        self.check_same_pile("ncplane_move_below", below)?;
+        nc_result("ncplane_move_below", unsafe {
+            crate::ncplane_move_below(self, below)
+        })?;
+        Ok(())
+    }
+
+    // Binding -----------------------------------------------------------------
+
+    /// Returns the identifier of the [NcPlane] this one is bound to, or `None`
+    /// if it's the root plane of its pile.
+    pub fn parent(&self) -> Option<NcPlaneId> {
+        let parent = unsafe { crate::ncplane_parent_const(self) };
+        if core::ptr::eq(parent, self) {
+            None
+        } else {
+            NcPlaneId::from_ptr(parent)
+        }
+    }
+
+    /// Returns the identifiers of the ancestors of this [NcPlane], from its
+    /// parent up to the root plane of its pile.
+    pub fn ancestors(&self) -> Vec<NcPlaneId> {
+        let mut ancestors = Vec::new();
+        let mut plane: *const NcPlane = self;
+        loop {
+            let parent = unsafe { crate::ncplane_parent_const(plane) };
+            // a root plane is its own parent
+            if parent.is_null() || parent == plane {
+                return ancestors;
+            }
+            ancestors.extend(NcPlaneId::from_ptr(parent));
+            plane = parent;
+        }
+    }
+
+    /// Returns the identifiers of the planes bound to this [NcPlane], in
+    /// z-order, from the top to the bottom.
+    pub fn children(&self) -> Vec<NcPlaneId> {
+        let id = self.id();
+        self.pile_planes()
+            .into_iter()
+            .filter(|p| {
+                *p != id && unsafe { crate::ncplane_parent_const(p.0.as_ptr()) } == id.0.as_ptr()
+            })
+            .collect()
+    }
+
+    fn as_mut_ptr(&self) -> *mut NcPlane {
+        self as *const NcPlane as *mut NcPlane
+    }
+
+    fn check_same_pile(&self, func: &'static str, other: &NcPlane) -> NcResult<()> {
+        let (this, other) = (self.as_mut_ptr(), other.as_mut_ptr());
+        if unsafe { crate::ncpile_top(this) != crate::ncpile_top(other) } {
+            return Err(NcError::Code {
+                func,
+                code: NCRESULT_ERR,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Writes to the current location of the plane, using the current style,
+/// so that `write!` can be used on it.
+///
+/// Writing fails at the plane boundary, unless scrolling is enabled.
+impl fmt::Write for NcPlane {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let cs = CString::new(s).map_err(|_| fmt::Error)?;
+        let res = unsafe { crate::ncplane_putstr_yx(self, -1, -1, cs.as_ptr()) };
+        putstr_result("ncplane_putstr_yx", s, res).map_err(|_| fmt::Error)?;
+        Ok(())
+    }
+}
+
+/// Converts the result of the `ncplane_putstr_*` functions into an [NcResult].
+///
+/// They return the negated count of what was written before an error, which
+/// is 0 if the first glyph fails, so 0 is an error unless `string` is empty.
+fn putstr_result(func: &'static str, string: &str, res: NcIntResult) -> NcResult<u32> {
+    if res < NCRESULT_OK || (res == NCRESULT_OK && !string.is_empty()) {
+        Err(NcError::Code { func, code: res })
+    } else {
+        Ok(res as u32)
     }
 }
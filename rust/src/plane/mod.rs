@@ -1,20 +1,20 @@
 //! `NcPlane`
 
-// functions already exported by bindgen : 105
+// functions already exported by bindgen : 106
 // ------------------------------------------
 // (#) 13 / 92 unit tests
-// (W) 1 wrapped as a method or function
+// (W) 30 wrapped as a method or function
 // ------------------------------------------
-//   ncpile_bottom
-// # ncpile_create
-//   ncpile_rasterize
-//   ncpile_render
-//   ncpile_top
-//   ncplane_above
+//W  ncpile_bottom
+//W# ncpile_create
+//W  ncpile_rasterize
+//W  ncpile_render
+//W  ncpile_top
+//W  ncplane_above
 //   ncplane_at_cursor
 //   ncplane_at_yx
 //   ncplane_base
-//   ncplane_below
+//W  ncplane_below
 //   ncplane_box
 //   ncplane_center_abs
 // # ncplane_channels
@@ -39,38 +39,39 @@
 // # ncplane_home
 //   ncplane_mergedown
 //   ncplane_mergedown_simple
-//   ncplane_move_above
-//   ncplane_move_below
-//   ncplane_move_bottom
-//   ncplane_move_top
+//W  ncplane_move_above
+//W  ncplane_move_below
+//W  ncplane_move_bottom
+//W  ncplane_move_top
 //   ncplane_move_yx
 //   ncplane_new
 // # ncplane_notcurses
 // # ncplane_notcurses_const
 //   ncplane_off_styles
 //   ncplane_on_styles
-//   ncplane_parent
-//   ncplane_parent_const
+//W  ncplane_parent
+//W  ncplane_parent_const
 //   ncplane_polyfill_yx
 //   ncplane_pulse
-//   ncplane_putchar_stained
+//W  ncplane_putchar_stained
 //   ncplane_putc_yx
-//   ncplane_putegc_stained
+//W  ncplane_putegc_stained
 //   ncplane_putegc_yx
 //   ncplane_putnstr_aligned
 //   ncplane_putnstr_yx
-//   ncplane_putstr_aligned
+//W  ncplane_putstr_aligned
 //   ncplane_putstr_stained
 //   ncplane_putstr_yx
-//   ncplane_puttext
+//W  ncplane_puttext
 //   ncplane_putwegc_stained
 //   ncplane_putwstr_stained
 //   ncplane_qrcode
-//   ncplane_reparent
-//   ncplane_reparent_family
+//W  ncplane_reparent
+//W  ncplane_reparent_family
 // # ncplane_resize
-//   ncplane_resizecb
-//   ncplane_resize_realign
+//W  ncplane_resizecb
+//   ncplane_resize_maximize
+//W  ncplane_resize_realign
 //   ncplane_rgba
 //   ncplane_rotate_ccw
 //   ncplane_rotate_cw
@@ -91,22 +92,22 @@
 //   ncplane_set_fg_rgb
 //   ncplane_set_fg_rgb8
 //   ncplane_set_fg_rgb8_clipped
-//   ncplane_set_resizecb
+//W  ncplane_set_resizecb
 //   ncplane_set_scrolling
 //   ncplane_set_styles
-//   ncplane_set_userptr
-//   ncplane_stain
+//W  ncplane_set_userptr
+//W  ncplane_stain
 //   ncplane_styles
 //   ncplane_styles_off
 //   ncplane_styles_on
 //   ncplane_styles_set
 //   ncplane_translate
 //   ncplane_translate_abs
-//   ncplane_userptr
+//W  ncplane_userptr
 //   ncplane_vline_interp
-//   ncplane_vprintf_aligned
-//   ncplane_vprintf_stained
-//   ncplane_vprintf_yx
+//W  ncplane_vprintf_aligned
+//W  ncplane_vprintf_stained
+//W  ncplane_vprintf_yx
 //   ncplane_x
 //   ncplane_y
 //   ncplane_yx
@@ -160,14 +161,24 @@
 // + ncplane_vline
 // + ncplane_vprintf
 //
-// NOTE: TODO: Still remains all the ncplane_printf* functions/macros (at the end)
+// NOTE: the ncplane_printf* functions are implemented with Rust formatting,
+// as the `NcPlane::printf*` methods and the `ncplane_printf*!` macros.
 
 #[cfg(test)]
 mod test;
 
+pub(crate) mod extra;
 mod methods;
 mod reimplemented;
+pub(crate) mod text;
+mod wrapped;
 pub use reimplemented::*;
+pub use wrapped::*;
+
+use core::{fmt, str::FromStr};
+use std::ffi::CString;
+
+use crate::NcParseError;
 
 // NcPlane
 /// Fundamental drawing surface.
@@ -227,6 +238,22 @@ pub type NcFdPlane = crate::bindings::ffi::ncfdplane;
 /// `type in C: ncplane_options (struct)`
 pub type NcFdPlaneOptions = crate::bindings::ffi::ncfdplane_options;
 
+/// A rectangular region of an [`NcPlane`].
+///
+/// Its origin is relative to the plane, and a region with 0 rows or columns
+/// is empty.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NcRect {
+    /// The row of the upper-left corner.
+    pub y: i32,
+    /// The column of the upper-left corner.
+    pub x: i32,
+    /// The number of rows.
+    pub rows: u32,
+    /// The number of columns.
+    pub cols: u32,
+}
+
 /// Alignment within a plane or terminal.
 /// Left/right-justified, or centered.
 pub type NcAlign = crate::bindings::ffi::ncalign_e;
@@ -260,6 +287,7 @@ pub const NCALIGN_UNALIGNED: NcAlign = crate::bindings::ffi::ncalign_e_NCALIGN_U
 /// - [`NCBLIT_DEFAULT`]
 /// - [`NCBLIT_SIXEL`]
 ///
+/// See also [`NcBlitterKind`], used by the safe API.
 pub type NcBlitter = crate::bindings::ffi::ncblitter_e;
 
 /// [`NcBlitter`] mode using: space, compatible with ASCII
@@ -294,3 +322,108 @@ pub const NCBLIT_DEFAULT: NcBlitter = crate::bindings::ffi::ncblitter_e_NCBLIT_D
 
 /// [`NcBlitter`] mode (not yet implemented)
 pub const NCBLIT_SIXEL: NcBlitter = crate::bindings::ffi::ncblitter_e_NCBLIT_SIXEL;
+
+/// Blitter Mode, used by the safe API.
+///
+/// We never blit full blocks, but instead spaces (more efficient) with the
+/// background set to the desired foreground.
+///
+/// It can be parsed from, and is displayed as, the names used by the
+/// [`notcurses_lex_blitter`][crate::notcurses_lex_blitter] &
+/// [`notcurses_str_blitter`][crate::notcurses_str_blitter] functions,
+/// e.g. `"ascii"`, `"halfblock"` or `"braille"`.
+///
+/// See also [`NcBlitter`], used by the C API.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NcBlitterKind {
+    /// The blitter is automatically chosen ([`NCBLIT_DEFAULT`]).
+    Default = NCBLIT_DEFAULT,
+
+    /// Space, compatible with ASCII ([`NCBLIT_1x1`]).
+    Ascii = NCBLIT_1x1,
+
+    /// Halves + 1x1 (space) ([`NCBLIT_2x1`]).
+    Half = NCBLIT_2x1,
+
+    /// Quadrants + 2x1 ([`NCBLIT_2x2`]).
+    Quadrant = NCBLIT_2x2,
+
+    /// Sextants ([`NCBLIT_3x2`]).
+    Sextant = NCBLIT_3x2,
+
+    /// Four vertical levels ([`NCBLIT_4x1`]).
+    FourStep = NCBLIT_4x1,
+
+    /// Eight vertical levels ([`NCBLIT_8x1`]).
+    EightStep = NCBLIT_8x1,
+
+    /// 4 rows, 2 cols ([`NCBLIT_BRAILLE`]).
+    Braille = NCBLIT_BRAILLE,
+
+    /// Not yet implemented ([`NCBLIT_SIXEL`]).
+    Sixel = NCBLIT_SIXEL,
+}
+
+impl NcBlitterKind {
+    /// All the blitters.
+    pub const ALL: [NcBlitterKind; 9] = [
+        NcBlitterKind::Default,
+        NcBlitterKind::Ascii,
+        NcBlitterKind::Half,
+        NcBlitterKind::Quadrant,
+        NcBlitterKind::Sextant,
+        NcBlitterKind::FourStep,
+        NcBlitterKind::EightStep,
+        NcBlitterKind::Braille,
+        NcBlitterKind::Sixel,
+    ];
+
+    /// Returns the blitter corresponding to an [`NcBlitter`], if any.
+    pub fn from_u32(blitter: NcBlitter) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|b| *b as NcBlitter == blitter)
+    }
+
+    /// Returns the name of the blitter, as returned by
+    /// [`notcurses_str_blitter`][crate::notcurses_str_blitter].
+    pub fn name(self) -> &'static str {
+        crate::error::nc_static_str(unsafe { crate::notcurses_str_blitter(self.into()) })
+    }
+}
+
+impl Default for NcBlitterKind {
+    fn default() -> Self {
+        NcBlitterKind::Default
+    }
+}
+
+impl From<NcBlitterKind> for NcBlitter {
+    fn from(blitter: NcBlitterKind) -> Self {
+        blitter as NcBlitter
+    }
+}
+
+impl fmt::Display for NcBlitterKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the name of a blitter, case insensitively,
+/// using [`notcurses_lex_blitter`][crate::notcurses_lex_blitter].
+impl FromStr for NcBlitterKind {
+    type Err = NcParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || NcParseError::new("NcBlitterKind", s);
+        let cs = CString::new(s).map_err(|_| err())?;
+        let mut blitter: NcBlitter = 0;
+        if unsafe { crate::notcurses_lex_blitter(cs.as_ptr(), &mut blitter) } < 0 {
+            return Err(err());
+        }
+        Self::from_u32(blitter).ok_or_else(err)
+    }
+}
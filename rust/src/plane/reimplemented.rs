@@ -11,8 +11,8 @@ use crate::{
     ncplane_cursor_move_yx, ncplane_cursor_yx, ncplane_dim_yx, ncplane_gradient,
     ncplane_hline_interp, ncplane_putc_yx, ncplane_putegc_yx, ncplane_putnstr_yx,
     ncplane_putstr_yx, ncplane_resize, ncplane_styles, ncplane_vline_interp, ncplane_vprintf_yx,
-    notcurses_align, NcAlign, NcAlphaBits, NcCell, NcChannel, NcChannelPair, NcColor, NcPlane,
-    NcResult, NcStyleMask, NCRESULT_ERR, NCRESULT_OK,
+    notcurses_align, NcAlign, NcAlphaBits, NcCell, NcChannel, NcChannelPair, NcColor, NcIntResult,
+    NcPlane, NcStyleMask, NCRESULT_ERR, NCRESULT_OK,
 };
 
 // Alpha -----------------------------------------------------------------------
@@ -99,13 +99,13 @@ pub fn ncplane_bg_default_p(plane: &NcPlane) -> bool {
 
 /// Calls ncplane_putc_yx() for the current cursor location.
 #[inline]
-pub fn ncplane_putc(plane: &mut NcPlane, cell: &NcCell) -> NcResult {
+pub fn ncplane_putc(plane: &mut NcPlane, cell: &NcCell) -> NcIntResult {
     unsafe { ncplane_putc_yx(plane, -1, -1, cell) }
 }
 
 /// Calls ncplane_putchar_yx() at the current cursor location.
 #[inline]
-pub fn ncplane_putchar(plane: &mut NcPlane, c: char) -> NcResult {
+pub fn ncplane_putchar(plane: &mut NcPlane, c: char) -> NcIntResult {
     ncplane_putchar_yx(plane, -1, -1, c)
 }
 
@@ -118,7 +118,7 @@ pub fn ncplane_putchar(plane: &mut NcPlane, c: char) -> NcResult {
 /// This works whether the underlying char is signed or unsigned.
 #[inline]
 // TODO: test char is < 8bit (currently 32bit)
-pub fn ncplane_putchar_yx(plane: &mut NcPlane, y: i32, x: i32, c: char) -> NcResult {
+pub fn ncplane_putchar_yx(plane: &mut NcPlane, y: i32, x: i32, c: char) -> NcIntResult {
     unsafe {
         let ce = NcCell::with_all(c, ncplane_styles(plane), ncplane_channels(plane));
         ncplane_putc_yx(plane, y, x, &ce)
@@ -127,25 +127,25 @@ pub fn ncplane_putchar_yx(plane: &mut NcPlane, y: i32, x: i32, c: char) -> NcRes
 
 /// Calls `ncplane_putegc()` at the current cursor location.
 #[inline]
-pub fn ncplane_putegc(plane: &mut NcPlane, gcluster: i8, sbytes: &mut i32) -> NcResult {
+pub fn ncplane_putegc(plane: &mut NcPlane, gcluster: i8, sbytes: &mut i32) -> NcIntResult {
     unsafe { ncplane_putegc_yx(plane, -1, -1, &gcluster, sbytes) }
 }
 
 ///
 #[inline]
-pub fn ncplane_putstr(plane: &mut NcPlane, string: &str) -> NcResult {
+pub fn ncplane_putstr(plane: &mut NcPlane, string: &str) -> NcIntResult {
     unsafe { ncplane_putstr_yx(plane, -1, -1, cstring![string]) }
 }
 
 ///
 #[inline]
-pub fn ncplane_putnstr(plane: &mut NcPlane, size: u64, gclustarr: &[u8]) -> NcResult {
+pub fn ncplane_putnstr(plane: &mut NcPlane, size: u64, gclustarr: &[u8]) -> NcIntResult {
     unsafe { ncplane_putnstr_yx(plane, -1, -1, size, cstring![gclustarr]) }
 }
 
 /// The [NcPlane] equivalent of `vprintf(3)`.
 #[inline]
-pub fn ncplane_vprintf(plane: &mut NcPlane, format: &str, ap: &mut __va_list_tag) -> NcResult {
+pub fn ncplane_vprintf(plane: &mut NcPlane, format: &str, ap: &mut __va_list_tag) -> NcIntResult {
     unsafe { ncplane_vprintf_yx(plane, -1, -1, cstring![format], ap) }
 }
 
@@ -155,12 +155,12 @@ pub fn ncplane_vprintf(plane: &mut NcPlane, format: &str, ap: &mut __va_list_tag
 ///
 /// This NcCell is invalidated if the associated NcPlane is destroyed.
 #[inline]
-pub fn ncplane_at_cursor_cell(plane: &mut NcPlane, cell: &mut NcCell) -> NcResult {
+pub fn ncplane_at_cursor_cell(plane: &mut NcPlane, cell: &mut NcCell) -> NcIntResult {
     let mut egc = unsafe { ncplane_at_cursor(plane, &mut cell.stylemask, &mut cell.channels) };
     if egc.is_null() {
         return NCRESULT_ERR;
     }
-    let result: NcResult = unsafe { cell_load(plane, cell, egc) };
+    let result: NcIntResult = unsafe { cell_load(plane, cell, egc) };
     if result != NCRESULT_OK {
         unsafe {
             free(&mut egc as *mut _ as *mut c_void);
@@ -172,13 +172,13 @@ pub fn ncplane_at_cursor_cell(plane: &mut NcPlane, cell: &mut NcCell) -> NcResul
 /// Retrieves the current contents of the specified cell into 'cell'.
 /// This cell is invalidated if the associated plane is destroyed.
 #[inline]
-pub fn ncplane_at_yx_cell(plane: &mut NcPlane, y: i32, x: i32, cell: &mut NcCell) -> NcResult {
+pub fn ncplane_at_yx_cell(plane: &mut NcPlane, y: i32, x: i32, cell: &mut NcCell) -> NcIntResult {
     let mut egc = unsafe { ncplane_at_yx(plane, y, x, &mut cell.stylemask, &mut cell.channels) };
     if egc.is_null() {
         return NCRESULT_ERR;
     }
     let channels = cell.channels; // need to preserve wide flag
-    let result: NcResult = unsafe { cell_load(plane, cell, egc) };
+    let result: NcIntResult = unsafe { cell_load(plane, cell, egc) };
     cell.channels = channels;
     unsafe {
         free(&mut egc as *mut _ as *mut c_void);
@@ -212,7 +212,7 @@ pub fn ncplane_dim_y(plane: &NcPlane) -> i32 {
 /// Resizes the plane, retaining what data we can (everything, unless we're
 /// shrinking in some dimension). Keep the origin where it is.
 #[inline]
-pub fn ncplane_resize_simple(plane: &mut NcPlane, ylen: i32, xlen: i32) -> NcResult {
+pub fn ncplane_resize_simple(plane: &mut NcPlane, ylen: i32, xlen: i32) -> NcIntResult {
     let (mut oldy, mut oldx) = (0, 0);
     unsafe {
         ncplane_dim_yx(plane, &mut oldy, &mut oldx);
@@ -273,7 +273,7 @@ pub fn ncplane_perimeter(
     hline: &NcCell,
     vline: &NcCell,
     ctlword: u32,
-) -> NcResult {
+) -> NcIntResult {
     unsafe {
         ncplane_cursor_move_yx(plane, 0, 0);
         let (mut dimy, mut dimx) = (0, 0);
@@ -289,7 +289,7 @@ pub fn ncplane_perimeter_double(
     stylemask: NcStyleMask,
     channels: NcChannelPair,
     ctlword: u32,
-) -> NcResult {
+) -> NcIntResult {
     if unsafe { ncplane_cursor_move_yx(plane, 0, 0) } != NCRESULT_OK {
         return NCRESULT_ERR;
     }
@@ -338,7 +338,7 @@ pub fn ncplane_perimeter_rounded(
     stylemask: NcStyleMask,
     channels: NcChannelPair,
     ctlword: u32,
-) -> NcResult {
+) -> NcIntResult {
     if unsafe { ncplane_cursor_move_yx(plane, 0, 0) } != NCRESULT_OK {
         return NCRESULT_ERR;
     }
@@ -397,7 +397,7 @@ pub fn ncplane_box_sized(
     ylen: i32,
     xlen: i32,
     ctlword: u32,
-) -> NcResult {
+) -> NcIntResult {
     let (mut y, mut x) = (0, 0);
     unsafe {
         ncplane_cursor_yx(plane, &mut y, &mut x);
@@ -425,7 +425,7 @@ pub fn ncplane_double_box(
     ystop: i32,
     xstop: i32,
     ctlword: u32,
-) -> NcResult {
+) -> NcIntResult {
     #[allow(unused_assignments)]
     let mut ret = NCRESULT_OK;
 
@@ -471,7 +471,7 @@ pub fn ncplane_double_box_sized(
     ylen: i32,
     xlen: i32,
     ctlword: u32,
-) -> NcResult {
+) -> NcIntResult {
     let (mut y, mut x) = (0, 0);
     unsafe {
         ncplane_cursor_yx(plane, &mut y, &mut x);
@@ -495,7 +495,7 @@ pub fn ncplane_rounded_box(
     ystop: i32,
     xstop: i32,
     ctlword: u32,
-) -> NcResult {
+) -> NcIntResult {
     #[allow(unused_assignments)]
     let mut ret = NCRESULT_OK;
 
@@ -540,7 +540,7 @@ pub fn ncplane_rounded_box_sized(
     ylen: i32,
     xlen: i32,
     ctlword: u32,
-) -> NcResult {
+) -> NcIntResult {
     let (mut y, mut x) = (0, 0);
     unsafe {
         ncplane_cursor_yx(plane, &mut y, &mut x);
@@ -574,7 +574,7 @@ pub fn ncplane_gradient_sized(
     lr: u64,
     ylen: i32,
     xlen: i32,
-) -> NcResult {
+) -> NcIntResult {
     if ylen < 1 || xlen < 1 {
         return NCRESULT_ERR;
     }
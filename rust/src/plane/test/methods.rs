@@ -1,4 +1,322 @@
 //! Test `NcPlane` methods and associated functions.
 
-// use crate::NcPlane;
-// use serial_test::serial;
+use core::fmt::Write;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use serial_test::serial;
+
+use crate::{
+    channels_fg_default_p, channels_fg_rgb, channels_set_fg_rgb, ncplane_resize_simple, FullMode,
+    NCBLIT_2x1, NcBlitterKind, NcError, NcPlane, NcRect, NCALIGN_LEFT, NCALIGN_RIGHT,
+};
+
+#[test]
+#[serial]
+fn ncplane_zorder() {
+    let nc = FullMode::new().unwrap();
+    let mut a = NcPlane::new(&nc, 0, 0, 4, 4).unwrap();
+    let mut b = NcPlane::new_bound(&mut a, 0, 0, 2, 2).unwrap();
+    let mut c = NcPlane::new_bound(&mut a, 1, 1, 2, 2).unwrap();
+    let (ia, ib, ic) = (a.id(), b.id(), c.id());
+
+    assert_eq![vec![ic, ib, ia], a.pile_planes()];
+    assert_eq![Some(ib), c.below()];
+    assert![c.above().is_none()];
+    assert_eq![(ic, ia), (a.top(), a.bottom())];
+
+    c.move_bottom();
+    assert_eq![vec![ib, ia, ic], a.pile_planes()];
+    c.move_top();
+    b.move_above(&mut c).unwrap();
+    assert_eq![vec![ib, ic, ia], a.pile_planes()];
+    b.move_below(&mut a).unwrap();
+    assert_eq![vec![ic, ia, ib], a.pile_planes()];
+
+    let mut other = NcPlane::new(&nc, 0, 0, 1, 1).unwrap();
+    assert![b.move_above(&mut other).is_err()];
+}
+
+#[test]
+#[serial]
+fn ncplane_tree() {
+    let nc = FullMode::new().unwrap();
+    let mut root = NcPlane::new(&nc, 0, 0, 4, 4).unwrap();
+    let mut child = NcPlane::new_bound(&mut root, 0, 0, 2, 2).unwrap();
+    let grandchild = NcPlane::new_bound(&mut child, 0, 0, 1, 1).unwrap();
+    let (ir, ic, ig) = (root.id(), child.id(), grandchild.id());
+
+    assert![root.parent().is_none()];
+    assert_eq![Some(ic), grandchild.parent()];
+    assert_eq![vec![ic, ir], grandchild.ancestors()];
+    assert_eq![vec![ic], root.children()];
+    assert_eq![vec![ig], child.children()];
+
+    let mut other = NcPlane::new(&nc, 0, 0, 4, 4).unwrap();
+    child.reparent_family(&mut other).unwrap();
+    assert![root.children().is_empty()];
+    assert_eq![vec![ic, other.id()], grandchild.ancestors()];
+
+    child.reparent(&mut root).unwrap();
+    assert_eq![vec![other.id()], grandchild.ancestors()];
+}
+
+#[test]
+#[serial]
+fn ncplane_drop_root() {
+    let nc = FullMode::new().unwrap();
+    let mut root = NcPlane::new(&nc, 0, 0, 4, 4).unwrap();
+    let mut child = NcPlane::new_bound(&mut root, 1, 1, 2, 2).unwrap();
+    let mut grandchild = NcPlane::new_bound(&mut child, 0, 0, 1, 1).unwrap();
+    drop(root);
+
+    // the child becomes the root of its own pile, along with its children
+    assert![child.parent().is_none()];
+    assert_eq![Some(child.id()), grandchild.parent()];
+    assert_eq![vec![grandchild.id(), child.id()], child.pile_planes()];
+    child.putstr_yx(0, 0, "hi").unwrap();
+    grandchild.putstr_yx(0, 0, "!").unwrap();
+
+    drop(child);
+    assert![grandchild.parent().is_none()];
+    assert_eq![vec![grandchild.id()], grandchild.pile_planes()];
+}
+
+#[test]
+#[serial]
+fn ncplane_resize_cb() {
+    let nc = FullMode::new().unwrap();
+    let mut parent = NcPlane::new(&nc, 0, 0, 4, 4).unwrap();
+    let mut child = NcPlane::new_bound(&mut parent, 0, 0, 1, 1).unwrap();
+    assert![!child.has_resize_cb()];
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    child.set_resize_cb(move |plane| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        plane.resize_maximize()
+    });
+    assert![child.has_resize_cb()];
+
+    assert_eq![0, ncplane_resize_simple(&mut parent, 6, 5)];
+    assert_eq![1, calls.load(Ordering::SeqCst)];
+    assert_eq![(6, 5), parent.dim_yx()];
+    assert_eq![parent.dim_yx(), child.dim_yx()];
+
+    // the closure is dropped along with the plane
+    drop(child);
+    assert_eq![1, Arc::strong_count(&calls)];
+}
+
+#[test]
+#[serial]
+fn ncplane_resize_builtin() {
+    let nc = FullMode::new().unwrap();
+    let mut parent = NcPlane::new(&nc, 0, 0, 4, 4).unwrap();
+    let mut child = NcPlane::new_bound(&mut parent, 0, 0, 1, 1).unwrap();
+
+    child.set_resize_maximize();
+    assert_eq![0, ncplane_resize_simple(&mut parent, 3, 7)];
+    assert_eq![(3, 7), parent.dim_yx()];
+    assert_eq![parent.dim_yx(), child.dim_yx()];
+
+    child.unset_resize_cb();
+    assert![!child.has_resize_cb()];
+    assert_eq![0, ncplane_resize_simple(&mut parent, 2, 2)];
+    assert_eq![(3, 7), child.dim_yx()];
+}
+
+#[test]
+#[serial]
+fn ncplane_user_data() {
+    let nc = FullMode::new().unwrap();
+    let mut plane = NcPlane::new(&nc, 0, 0, 1, 1).unwrap();
+    assert![!plane.has_user_data()];
+    assert_eq![None, plane.user_data::<u32>()];
+
+    plane.set_user_data(7_u32);
+    assert![plane.has_user_data()];
+    assert_eq![None, plane.user_data::<i64>()];
+    assert_eq![None, plane.take_user_data::<i64>()];
+    *plane.user_data_mut::<u32>().unwrap() += 1;
+    assert_eq![Some(&8), plane.user_data::<u32>()];
+    assert_eq![Some(8), plane.take_user_data::<u32>()];
+    assert![!plane.has_user_data()];
+
+    // the data is dropped when replaced, and along with the plane
+    let model = Arc::new(());
+    plane.set_user_data(Arc::clone(&model));
+    plane.set_user_data(Arc::clone(&model));
+    assert_eq![2, Arc::strong_count(&model)];
+    drop(plane);
+    assert_eq![1, Arc::strong_count(&model)];
+}
+
+#[test]
+#[serial]
+fn ncplane_fmt_write() {
+    let nc = FullMode::new().unwrap();
+    let mut plane = NcPlane::new(&nc, 0, 0, 2, 10).unwrap();
+
+    write!(plane, "{}:{:>3}", "ab", 7).unwrap();
+    assert_eq![(0, 6), plane.cursor_yx()];
+    // it fails at the plane boundary
+    assert![write!(plane, "{}", "overflow").is_err()];
+    assert_eq![
+        Err(NcError::NulByte {
+            func: "ncplane_putstr_yx"
+        }),
+        plane.putstr_yx(1, 0, "a\0b")
+    ];
+}
+
+#[test]
+#[serial]
+fn ncplane_printf_macros() {
+    let nc = FullMode::new().unwrap();
+    let mut plane = NcPlane::new(&nc, 0, 0, 3, 10).unwrap();
+
+    assert_eq![Ok(3), crate::ncplane_printf!(&mut plane, "{}", 123)];
+    assert_eq![
+        Ok(2),
+        crate::ncplane_printf_yx!(&mut plane, 1, 4, "{}{}", 'a', 'b')
+    ];
+    assert_eq![(1, 6), plane.cursor_yx()];
+    assert_eq![
+        Ok(4),
+        crate::ncplane_printf_aligned!(&mut plane, 2, NCALIGN_RIGHT, "{:04}", 5)
+    ];
+    assert_eq![(2, 10), plane.cursor_yx()];
+    // nothing can be written at the end of the plane
+    assert_eq![
+        Err(NcError::Code {
+            func: "ncplane_putstr_yx",
+            code: 0
+        }),
+        crate::ncplane_printf!(&mut plane, "x")
+    ];
+    assert_eq![0, unsafe {
+        crate::ncplane_cursor_move_yx(&mut *plane, 0, 0)
+    }];
+    assert_eq![
+        Ok(2),
+        crate::ncplane_printf_stained!(&mut plane, "{:x}", 255)
+    ];
+}
+
+#[test]
+#[serial]
+fn ncplane_puttext() {
+    let nc = FullMode::new().unwrap();
+    let mut plane = NcPlane::new(&nc, 0, 0, 2, 6).unwrap();
+
+    let text = "short";
+    assert_eq![Ok(text.len()), plane.puttext(0, NCALIGN_LEFT, text)];
+
+    // it reports how much was consumed when it runs out of room
+    plane.erase();
+    let text = "one two three four";
+    let consumed = plane.puttext(0, NCALIGN_LEFT, text).unwrap();
+    assert![consumed > 0 && consumed < text.len()];
+}
+
+#[test]
+#[serial]
+fn ncplane_put_paragraph() {
+    let nc = FullMode::new().unwrap();
+    let mut plane = NcPlane::new(&nc, 0, 0, 4, 12).unwrap();
+
+    let rect = NcRect::new(1, 2, 2, 8);
+    let text = "one two three four five";
+    let consumed = plane
+        .put_paragraph(rect, NCALIGN_RIGHT, text, false)
+        .unwrap();
+    assert_eq!["one two three".len(), consumed];
+    // the last line is right aligned
+    assert_eq![(2, 10), plane.cursor_yx()];
+
+    let consumed = plane.put_paragraph(rect, NCALIGN_LEFT, text, true).unwrap();
+    assert_eq!["one two three".len(), consumed];
+}
+
+#[test]
+#[serial]
+fn ncplane_stained() {
+    let nc = FullMode::new().unwrap();
+    let mut plane = NcPlane::new(&nc, 0, 0, 3, 10).unwrap();
+    plane.putstr("0123456789").unwrap();
+
+    assert_eq![0, unsafe {
+        crate::ncplane_cursor_move_yx(&mut *plane, 0, 0)
+    }];
+    assert_eq![Ok(3), plane.putstr_stained("abc")];
+    assert_eq![Ok(1), plane.putchar_stained('d')];
+    assert_eq![Ok(1), plane.putchar_stained('é')];
+    assert_eq![Ok((1, 1)), plane.putegc_stained("fg")];
+    assert_eq![(0, 6), plane.cursor_yx()];
+
+    // the cursor is past the last column after writing to it
+    assert_eq![0, unsafe {
+        crate::ncplane_cursor_move_yx(&mut *plane, 2, 8)
+    }];
+    assert_eq![
+        Err(NcError::Code {
+            func: "ncplane_putstr_stained",
+            code: -2
+        }),
+        plane.putstr_stained("xyz")
+    ];
+    assert_eq![(2, 10), plane.cursor_yx()];
+    assert![plane.putchar_stained('x').is_err()];
+}
+
+#[test]
+#[serial]
+fn ncplane_stain() {
+    let nc = FullMode::new().unwrap();
+    let mut plane = NcPlane::new(&nc, 0, 0, 3, 10).unwrap();
+    plane.putstr_yx(1, 0, "highlight").unwrap();
+    let cursor = plane.cursor_yx();
+
+    let (mut red, mut blue) = (0, 0);
+    channels_set_fg_rgb(&mut red, 0xff0000);
+    channels_set_fg_rgb(&mut blue, 0x0000ff);
+    let fg_at = |plane: &NcPlane, y, x| {
+        let (mut stylemask, mut channels) = (0, 0);
+        unsafe {
+            let egc = crate::ncplane_at_yx(plane, y, x, &mut stylemask, &mut channels);
+            libc::free(egc as *mut _);
+        }
+        assert![!channels_fg_default_p(channels)];
+        channels_fg_rgb(channels)
+    };
+
+    let row = NcRect::new(1, 0, 1, 10);
+    assert_eq![Ok(10), plane.stain(row, red, blue, red, blue)];
+    assert_eq![0xff0000, fg_at(&plane, 1, 0)];
+    assert_eq![0x0000ff, fg_at(&plane, 1, 9)];
+
+    assert_eq![Ok(4), plane.stain_uniform(NcRect::new(0, 0, 2, 2), blue)];
+    assert_eq![0x0000ff, fg_at(&plane, 0, 0)];
+    assert_eq![0x0000ff, fg_at(&plane, 1, 0)];
+    assert_eq![Ok(0), plane.stain_uniform(NcRect::new(0, 0, 0, 2), red)];
+    // the cursor is left where it was
+    assert_eq![cursor, plane.cursor_yx()];
+}
+
+#[test]
+fn ncblitter_lex() {
+    for blitter in NcBlitterKind::ALL.iter() {
+        assert_eq![Ok(*blitter), blitter.to_string().parse()];
+    }
+    assert_eq!["halfblock", NcBlitterKind::Half.to_string()];
+    assert_eq![Ok(NcBlitterKind::Braille), "BRAILLE".parse()];
+    assert_eq![
+        Some(NcBlitterKind::Half),
+        NcBlitterKind::from_u32(NCBLIT_2x1)
+    ];
+    assert!["2x1".parse::<NcBlitterKind>().is_err()];
+    assert!["ascii\0".parse::<NcBlitterKind>().is_err()];
+}
@@ -5,3 +5,6 @@ mod methods;
 
 #[cfg(test)]
 mod reimplemented;
+
+#[cfg(test)]
+mod text;
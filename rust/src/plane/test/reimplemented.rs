@@ -14,8 +14,9 @@ fn ncplane_options() {
 fn ncpile_create() {
     let po = NcPlaneOptions::new(0, 0, 20, 20);
     unsafe {
-        let nc = Notcurses::new();
-        let _p = NcPlane::with_options(nc, &po);
+        let nc = Notcurses::new().unwrap();
+        let p = NcPlane::with_options(nc, &po).unwrap();
+        p.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -24,15 +25,17 @@ fn ncpile_create() {
 #[serial]
 fn ncplane_notcurses() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
+        let nc = Notcurses::new().unwrap();
+        let nc_ptr = nc as *mut Notcurses;
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 
-        let nc2 = crate::ncplane_notcurses(plane);
-        assert_eq![nc as *mut _, nc2];
+        let nc2 = crate::ncplane_notcurses(&mut *plane);
+        assert_eq![nc_ptr, nc2];
 
-        let nc3 = crate::ncplane_notcurses_const(plane);
-        assert_eq![nc as *const _, nc3];
+        let nc3 = crate::ncplane_notcurses_const(&*plane);
+        assert_eq![nc_ptr as *const _, nc3];
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -41,31 +44,32 @@ fn ncplane_notcurses() {
 #[serial]
 fn ncplane_cursor() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
+        let nc = Notcurses::new().unwrap();
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 
         let (mut y, mut x) = (0, 0);
-        crate::ncplane_cursor_yx(plane, &mut y, &mut x);
+        crate::ncplane_cursor_yx(&*plane, &mut y, &mut x);
         assert_eq![x, 0];
         assert_eq![y, 0];
 
-        let res = crate::ncplane_cursor_move_yx(plane, 10, 15);
+        let res = crate::ncplane_cursor_move_yx(&mut *plane, 10, 15);
         assert_eq![res, 0];
-        crate::ncplane_cursor_yx(plane, &mut y, &mut x);
+        crate::ncplane_cursor_yx(&*plane, &mut y, &mut x);
         assert_eq![x, 15];
         assert_eq![y, 10];
 
-        crate::ncplane_home(plane);
-        crate::ncplane_cursor_yx(plane, &mut y, &mut x);
+        crate::ncplane_home(&mut *plane);
+        crate::ncplane_cursor_yx(&*plane, &mut y, &mut x);
         assert_eq![x, 0];
         assert_eq![y, 0];
 
-        let _res = crate::ncplane_cursor_move_yx(plane, 10, 15);
-        crate::ncplane_erase(plane); // has to move the cursor to 0,0
-        crate::ncplane_cursor_yx(plane, &mut y, &mut x);
+        let _res = crate::ncplane_cursor_move_yx(&mut *plane, 10, 15);
+        crate::ncplane_erase(&mut *plane); // has to move the cursor to 0,0
+        crate::ncplane_cursor_yx(&*plane, &mut y, &mut x);
         assert_eq![x, 0];
         assert_eq![y, 0];
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -74,15 +78,16 @@ fn ncplane_cursor() {
 #[serial]
 fn ncplane_channels() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
+        let nc = Notcurses::new().unwrap();
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 
-        let channels = crate::ncplane_channels(plane);
+        let channels = crate::ncplane_channels(&*plane);
         assert_eq![channels, 0];
 
-        crate::ncplane_set_channels(plane, 0x1122334455667788);
-        assert_eq![0x1122334455667788, crate::ncplane_channels(plane)];
+        crate::ncplane_set_channels(&mut *plane, 0x1122334455667788);
+        assert_eq![0x1122334455667788, crate::ncplane_channels(&*plane)];
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -91,17 +96,18 @@ fn ncplane_channels() {
 #[serial]
 fn ncplane_fchannel() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
+        let nc = Notcurses::new().unwrap();
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 
-        crate::ncplane_set_channels(plane, 0x1122334455667788);
-        let channels = crate::ncplane_channels(plane);
+        crate::ncplane_set_channels(&mut *plane, 0x1122334455667788);
+        let channels = crate::ncplane_channels(&*plane);
         assert_eq![0x11223344, crate::channels_fchannel(channels)];
 
-        let channels = crate::ncplane_set_fchannel(plane, 0x10203040);
+        let channels = crate::ncplane_set_fchannel(&mut *plane, 0x10203040);
         assert_eq![0x10203040, crate::channels_fchannel(channels)];
         assert_eq![0x1020304055667788, channels];
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -110,20 +116,21 @@ fn ncplane_fchannel() {
 #[serial]
 fn ncplane_bchannel() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
+        let nc = Notcurses::new().unwrap();
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 
-        crate::ncplane_set_channels(plane, 0x1122334455667788);
-        let channels = crate::ncplane_channels(plane);
+        crate::ncplane_set_channels(&mut *plane, 0x1122334455667788);
+        let channels = crate::ncplane_channels(&*plane);
         assert_eq![0x55667788, crate::channels_bchannel(channels)];
 
         // BUG? ncplane_set_bchannel and ncplane_set_fchannel don't get
         // applied unless they are assigned to a variable. Weird.
 
-        let channels = crate::ncplane_set_bchannel(plane, 0x50607080);
+        let channels = crate::ncplane_set_bchannel(&mut *plane, 0x50607080);
         assert_eq![0x50607080, crate::channels_bchannel(channels)];
         assert_eq![0x1122334450607080, channels];
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -132,12 +139,13 @@ fn ncplane_bchannel() {
 #[serial]
 fn ncplane_rgb() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
+        let nc = Notcurses::new().unwrap();
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 
-        crate::ncplane_set_fg_rgb(plane, 0x112233);
-        assert_eq![0x112233, crate::ncplane_fg_rgb(plane)];
+        crate::ncplane_set_fg_rgb(&mut *plane, 0x112233);
+        assert_eq![0x112233, crate::ncplane_fg_rgb(&*plane)];
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -146,21 +154,22 @@ fn ncplane_rgb() {
 #[serial]
 fn ncplane_default() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
-        assert_eq![true, crate::ncplane_bg_default_p(plane)];
-        assert_eq![true, crate::ncplane_fg_default_p(plane)];
-
-        crate::ncplane_set_bg_rgb8(plane, 11, 22, 33);
-        crate::ncplane_set_fg_rgb8(plane, 44, 55, 66);
-        assert_eq![false, crate::ncplane_bg_default_p(plane)];
-        assert_eq![false, crate::ncplane_fg_default_p(plane)];
-
-        crate::ncplane_set_bg_default(plane);
-        crate::ncplane_set_fg_default(plane);
-        assert_eq![true, crate::ncplane_bg_default_p(plane)];
-        assert_eq![true, crate::ncplane_fg_default_p(plane)];
-
+        let nc = Notcurses::new().unwrap();
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
+        assert_eq![true, crate::ncplane_bg_default_p(&*plane)];
+        assert_eq![true, crate::ncplane_fg_default_p(&*plane)];
+
+        crate::ncplane_set_bg_rgb8(&mut *plane, 11, 22, 33);
+        crate::ncplane_set_fg_rgb8(&mut *plane, 44, 55, 66);
+        assert_eq![false, crate::ncplane_bg_default_p(&*plane)];
+        assert_eq![false, crate::ncplane_fg_default_p(&*plane)];
+
+        crate::ncplane_set_bg_default(&mut *plane);
+        crate::ncplane_set_fg_default(&mut *plane);
+        assert_eq![true, crate::ncplane_bg_default_p(&*plane)];
+        assert_eq![true, crate::ncplane_fg_default_p(&*plane)];
+
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -169,16 +178,17 @@ fn ncplane_default() {
 #[serial]
 fn ncplane_dimensions() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 10, 20);
+        let nc = Notcurses::new().unwrap();
+        let plane = NcPlane::new(nc, 0, 0, 10, 20).unwrap();
 
         let (mut y, mut x) = (0, 0);
-        crate::ncplane_dim_yx(plane, &mut y, &mut x);
+        crate::ncplane_dim_yx(&*plane, &mut y, &mut x);
         assert_eq!((10, 20), (y, x));
 
-        assert_eq!(10, crate::ncplane_dim_y(plane));
-        assert_eq!(20, crate::ncplane_dim_x(plane));
+        assert_eq!(10, crate::ncplane_dim_y(&*plane));
+        assert_eq!(20, crate::ncplane_dim_x(&*plane));
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -187,23 +197,24 @@ fn ncplane_dimensions() {
 #[serial]
 fn ncplane_resize() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
+        let nc = Notcurses::new().unwrap();
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 
-        let res = crate::ncplane_resize_simple(plane, 40, 40);
+        let res = crate::ncplane_resize_simple(&mut *plane, 40, 40);
         assert_eq![NCRESULT_OK, res];
 
         let (mut y, mut x) = (0, 0);
-        crate::ncplane_dim_yx(plane, &mut y, &mut x);
+        crate::ncplane_dim_yx(&*plane, &mut y, &mut x);
         assert_eq!((40, 40), (y, x));
 
         // TODO: test further plane subset keeping unchanged features
-        let res = crate::ncplane_resize(plane, 0, 0, 0, 0, 0, 0, 60, 70);
+        let res = crate::ncplane_resize(&mut *plane, 0, 0, 0, 0, 0, 0, 60, 70);
         assert_eq![NCRESULT_OK, res];
 
-        assert_eq!(60, crate::ncplane_dim_y(plane));
-        assert_eq!(70, crate::ncplane_dim_x(plane));
+        assert_eq!(60, crate::ncplane_dim_y(&*plane));
+        assert_eq!(70, crate::ncplane_dim_x(&*plane));
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -216,20 +227,21 @@ fn ncplane_resize() {
 // The base cell is preserved.
 fn ncplane_erase() {
     unsafe {
-        let nc = Notcurses::new();
-        let plane = NcPlane::new(nc, 0, 0, 20, 20);
+        let nc = Notcurses::new().unwrap();
+        let mut plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 
-        crate::ncplane_set_bg_rgb(plane, 0x112233);
-        crate::ncplane_set_fg_rgb(plane, 0x445566);
-        assert_eq![false, crate::ncplane_bg_default_p(plane)];
-        assert_eq![false, crate::ncplane_fg_default_p(plane)];
+        crate::ncplane_set_bg_rgb(&mut *plane, 0x112233);
+        crate::ncplane_set_fg_rgb(&mut *plane, 0x445566);
+        assert_eq![false, crate::ncplane_bg_default_p(&*plane)];
+        assert_eq![false, crate::ncplane_fg_default_p(&*plane)];
 
         // FIXME? DEBUG
-        crate::ncplane_erase(plane);
-        // assert_eq![true, crate::ncplane_bg_default_p(plane)];
-        // assert_eq![true, crate::ncplane_fg_default_p(plane)];
-        //print!(" C: {:#0x} ", crate::ncplane_channels(plane));
+        crate::ncplane_erase(&mut *plane);
+        // assert_eq![true, crate::ncplane_bg_default_p(&*plane)];
+        // assert_eq![true, crate::ncplane_fg_default_p(&*plane)];
+        //print!(" C: {:#0x} ", crate::ncplane_channels(&*plane));
 
+        plane.destroy().unwrap();
         notcurses_stop(nc);
     }
 }
@@ -238,8 +250,8 @@ fn ncplane_erase() {
 // #[serial]
 // fn ncplane_at_cursor() {
 //     unsafe {
-//         let nc = Notcurses::new();
-//         let plane = NcPlane::new(nc, 0, 0, 20, 20);
+//         let nc = Notcurses::new().unwrap();
+//         let plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 //
 //         notcurses_stop(nc);
 //     }
@@ -249,8 +261,8 @@ fn ncplane_erase() {
 // #[serial]
 // fn ncplane_at_cursor_cell() {
 //     unsafe {
-//         let nc = Notcurses::new();
-//         let plane = NcPlane::new(nc, 0, 0, 20, 20);
+//         let nc = Notcurses::new().unwrap();
+//         let plane = NcPlane::new(nc, 0, 0, 20, 20).unwrap();
 //
 //         notcurses_stop(nc);
 //     }
@@ -0,0 +1,75 @@
+//! Test the paragraph layout.
+
+use crate::plane::text::layout;
+
+fn width(s: &str) -> u32 {
+    s.chars().count() as u32
+}
+
+fn lines(text: &str, rows: u32, cols: u32, ellipsis: bool) -> (Vec<String>, usize) {
+    let (lines, consumed) = layout(text, rows, cols, ellipsis, width);
+    (lines.into_iter().map(|l| l.text).collect(), consumed)
+}
+
+#[test]
+fn layout_wraps_at_whitespace() {
+    let text = "the quick  brown fox jumps";
+    assert_eq![
+        (
+            vec!["the quick".into(), "brown fox".into(), "jumps".into()],
+            text.len()
+        ),
+        lines(text, 5, 10, false)
+    ];
+}
+
+#[test]
+fn layout_newlines() {
+    let text = "ab\n\ncd\n";
+    assert_eq![
+        (vec!["ab".into(), "".into(), "cd".into()], text.len()),
+        lines(text, 5, 10, false)
+    ];
+}
+
+#[test]
+fn layout_breaks_long_words() {
+    assert_eq![
+        (vec!["abcd".into(), "efgh".into(), "ij k".into()], 12),
+        lines("abcdefghij k", 3, 4, false)
+    ];
+}
+
+#[test]
+fn layout_reports_consumed() {
+    let text = "one two three four";
+    let (first, consumed) = lines(text, 1, 8, false);
+    assert_eq![vec!["one two".to_string()], first];
+    assert_eq![7, consumed];
+    assert_eq![
+        (vec!["three".into(), "four".into()], text.len() - consumed),
+        lines(&text[consumed..], 2, 8, false)
+    ];
+}
+
+#[test]
+fn layout_ellipsis() {
+    // the last line has room for the ellipsis
+    assert_eq![(vec!["one…".into()], 3), lines("one two", 1, 5, true)];
+    // the last line is truncated to make room for it
+    assert_eq![
+        (vec!["one tw…".into()], 6),
+        lines("one two three", 1, 7, true)
+    ];
+    // no space is left before it
+    assert_eq![(vec!["one…".into()], 3), lines("one two three", 1, 5, true)];
+    // it's not added when everything fits
+    assert_eq![(vec!["one two".into()], 8), lines("one two ", 1, 7, true)];
+}
+
+#[test]
+fn layout_empty() {
+    assert_eq![(vec![], 0), lines("abc", 0, 5, true)];
+    assert_eq![(vec![], 0), lines("abc", 2, 0, false)];
+    assert_eq![(vec![], 0), lines("", 2, 5, false)];
+}
@@ -0,0 +1,176 @@
+//! Paragraph layout for `NcPlane::put_paragraph`.
+
+use std::ffi::CString;
+
+/// A laid out line of text, with whitespace runs collapsed to single spaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NcTextLine {
+    pub(crate) text: String,
+    pub(crate) width: u32,
+
+    // the position in the source text where the line starts,
+    // and right after each of its characters.
+    start: usize,
+    ends: Vec<usize>,
+}
+
+impl NcTextLine {
+    fn new(start: usize) -> Self {
+        Self {
+            text: String::new(),
+            width: 0,
+            start,
+            ends: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, c: char, width: u32, end: usize) {
+        self.text.push(c);
+        self.width += width;
+        self.ends.push(end);
+    }
+
+    /// Removes the last character, returning its width.
+    fn pop<W: Fn(&str) -> u32>(&mut self, width: &W) -> Option<u32> {
+        let c = self.text.pop()?;
+        self.ends.pop();
+        let w = width(c.encode_utf8(&mut [0; 4]));
+        self.width = self.width.saturating_sub(w);
+        Some(w)
+    }
+
+    /// Returns the position in the source text right after the line.
+    fn end(&self) -> usize {
+        self.ends.last().copied().unwrap_or(self.start)
+    }
+}
+
+/// The ellipsis appended to a truncated paragraph.
+pub(crate) const ELLIPSIS: char = '…';
+
+/// Returns the number of columns `s` occupies, according to `ncstrwidth`.
+///
+/// Nonprintable strings occupy no columns.
+pub(crate) fn ncstrwidth(s: &str) -> u32 {
+    match CString::new(s) {
+        Ok(cs) => unsafe { crate::ncstrwidth(cs.as_ptr()) }.max(0) as u32,
+        Err(_) => 0,
+    }
+}
+
+/// Lays out `text` in up to `rows` lines of up to `cols` columns, measuring
+/// the strings with `width`.
+///
+/// Lines are broken at whitespace, and at newlines, which also start a new
+/// paragraph. Words wider than a line are broken wherever they reach its end,
+/// without adding a hyphen. If `ellipsis` is true and the text doesn't fit,
+/// the last line is truncated to end with an [`ELLIPSIS`].
+///
+/// Returns the lines and the number of bytes of `text` that were consumed.
+pub(crate) fn layout<W>(
+    text: &str,
+    rows: u32,
+    cols: u32,
+    ellipsis: bool,
+    width: W,
+) -> (Vec<NcTextLine>, usize)
+where
+    W: Fn(&str) -> u32,
+{
+    let mut lines = Vec::new();
+    let mut pos = 0;
+    if cols == 0 {
+        return (lines, pos);
+    }
+
+    while pos < text.len() && (lines.len() as u32) < rows {
+        pos = skip_blanks(text, pos);
+        let mut line = NcTextLine::new(pos);
+        loop {
+            let word_start = skip_blanks(text, pos);
+            match text[word_start..].chars().next() {
+                None => {
+                    pos = word_start;
+                    break;
+                }
+                Some('\n') => {
+                    pos = word_start + 1;
+                    break;
+                }
+                Some(_) => (),
+            }
+            let word_end = text[word_start..]
+                .find(char::is_whitespace)
+                .map_or(text.len(), |i| word_start + i);
+            let word = &text[word_start..word_end];
+            let gap = if line.text.is_empty() { 0 } else { 1 };
+            let word_width = width(word);
+
+            if line.width + gap + word_width <= cols {
+                if gap > 0 {
+                    line.push(' ', gap, word_start);
+                }
+                for (i, c) in word.char_indices() {
+                    line.push(c, 0, word_start + i + c.len_utf8());
+                }
+                line.width += word_width;
+                pos = word_end;
+            } else if line.text.is_empty() {
+                // the word doesn't fit in a line of its own
+                push_fitting(&mut line, word_start, word, cols, &width);
+                pos = line.end();
+                break;
+            } else {
+                break;
+            }
+        }
+        lines.push(line);
+    }
+
+    // whitespace left over after the last line is consumed with it
+    if text[pos..].trim().is_empty() {
+        pos = text.len();
+    } else if ellipsis {
+        if let Some(line) = lines.last_mut() {
+            let ellipsis_width = width(ELLIPSIS.encode_utf8(&mut [0; 4]));
+            if line.width + ellipsis_width > cols {
+                while line.width + ellipsis_width > cols && line.pop(&width).is_some() {}
+                // don't leave a space before the ellipsis
+                if line.text.ends_with(' ') {
+                    line.pop(&width);
+                }
+                pos = line.end();
+            }
+            line.text.push(ELLIPSIS);
+            line.width += ellipsis_width;
+        }
+    }
+    (lines, pos)
+}
+
+/// Returns the position of the first non-blank character at or after `pos`,
+/// with newlines not counting as blanks.
+fn skip_blanks(text: &str, pos: usize) -> usize {
+    text[pos..]
+        .find(|c: char| c == '\n' || !c.is_whitespace())
+        .map_or(text.len(), |i| pos + i)
+}
+
+/// Pushes as much of `word`, which starts at `start` in the source text, as
+/// fits in the rest of `line`, keeping zero-width characters with the one
+/// they follow, and at least one character.
+fn push_fitting<W: Fn(&str) -> u32>(
+    line: &mut NcTextLine,
+    start: usize,
+    word: &str,
+    cols: u32,
+    width: &W,
+) {
+    for (i, c) in word.char_indices() {
+        let w = width(c.encode_utf8(&mut [0; 4]));
+        if w > 0 && line.width + w > cols && !line.text.is_empty() {
+            break;
+        }
+        line.push(c, w, start + i + c.len_utf8());
+    }
+}
@@ -0,0 +1,188 @@
+//! `NcPlaneRef` & `NcPlaneOwned` wrapper structs and traits implementations.
+
+use core::{
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{
+    error::{nc_ptr, nc_result},
+    plane::extra::NcPlaneExtra,
+    NcPlane, NcResult, Notcurses,
+};
+
+/// A handle to an [`NcPlane`] that can't outlive its [`Notcurses`] context.
+///
+/// Implemented by [`NcPlaneRef`] and [`NcPlaneOwned`], so that any of them
+/// can be used as the parent of a new bound plane, which will be tied to the
+/// same context lifetime `'nc`.
+pub trait NcPlaneHandle<'nc>: DerefMut<Target = NcPlane> {}
+
+/// A borrowed [`NcPlane`], that can't outlive its [`Notcurses`] context.
+///
+/// It's returned for planes that are owned by the context, like the
+/// standard plane, and therefore are not destroyed when dropped.
+#[derive(Debug)]
+pub struct NcPlaneRef<'nc> {
+    raw: NonNull<NcPlane>,
+    _nc: PhantomData<&'nc Notcurses>,
+}
+
+impl<'nc> NcPlaneRef<'nc> {
+    /// Wraps a pointer to a plane owned by a context that lives for `'nc`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid for the whole `'nc` lifetime.
+    pub(crate) unsafe fn from_ptr(raw: NonNull<NcPlane>) -> Self {
+        Self {
+            raw,
+            _nc: PhantomData,
+        }
+    }
+}
+
+impl<'nc> NcPlaneHandle<'nc> for NcPlaneRef<'nc> {}
+
+impl<'nc> Deref for NcPlaneRef<'nc> {
+    type Target = NcPlane;
+
+    fn deref(&self) -> &NcPlane {
+        unsafe { self.raw.as_ref() }
+    }
+}
+
+impl<'nc> DerefMut for NcPlaneRef<'nc> {
+    fn deref_mut(&mut self) -> &mut NcPlane {
+        unsafe { self.raw.as_mut() }
+    }
+}
+
+/// An owned [`NcPlane`], that is destroyed when dropped, and can't outlive
+/// its [`Notcurses`] context.
+///
+/// The Rust data attached to the plane, like its user data and resize
+/// callback, is dropped along with it.
+///
+/// Any planes bound to it are reparented to its parent when it's destroyed,
+/// or become the roots of their own piles if it was a root plane, so they
+/// only need to be tied to the context lifetime `'nc`.
+#[derive(Debug)]
+pub struct NcPlaneOwned<'nc> {
+    raw: NonNull<NcPlane>,
+    _nc: PhantomData<&'nc Notcurses>,
+}
+
+impl<'nc> NcPlaneOwned<'nc> {
+    /// Takes ownership of a new plane created in a context that lives for `'nc`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be a newly created plane, not owned by anything else,
+    /// and must not be the standard plane, which can't be destroyed.
+    pub(crate) unsafe fn from_ptr(raw: NonNull<NcPlane>) -> Self {
+        Self {
+            raw,
+            _nc: PhantomData,
+        }
+    }
+
+    /// Destroys the plane.
+    ///
+    /// Use this instead of letting it drop when you need to check the result.
+    pub fn destroy(self) -> NcResult<()> {
+        let this = ManuallyDrop::new(self);
+        nc_result("ncplane_destroy", unsafe { destroy_raw(this.raw.as_ptr()) })?;
+        Ok(())
+    }
+
+    /// Binds the plane to `new_parent`, moving it to its pile if needed.
+    ///
+    /// Any planes bound to this plane are rebound to its previous parent.
+    /// The plane is placed immediately atop its new parent on the z-axis.
+    pub fn reparent(&mut self, new_parent: &mut impl NcPlaneHandle<'nc>) -> NcResult<()> {
+        nc_ptr("ncplane_reparent", unsafe {
+            crate::ncplane_reparent(self.raw.as_ptr(), &mut **new_parent)
+        })?;
+        Ok(())
+    }
+
+    /// Binds the plane to `new_parent`, moving it to its pile if needed,
+    /// along with all the planes bound to it, whose relative z-order is
+    /// maintained.
+    pub fn reparent_family(&mut self, new_parent: &mut impl NcPlaneHandle<'nc>) -> NcResult<()> {
+        nc_ptr("ncplane_reparent_family", unsafe {
+            crate::ncplane_reparent_family(self.raw.as_ptr(), &mut **new_parent)
+        })?;
+        Ok(())
+    }
+}
+
+impl<'nc> NcPlaneHandle<'nc> for NcPlaneOwned<'nc> {}
+
+impl<'nc> Drop for NcPlaneOwned<'nc> {
+    fn drop(&mut self) {
+        let _ = unsafe { destroy_raw(self.raw.as_ptr()) };
+    }
+}
+
+/// Destroys a plane along with its Rust data.
+///
+/// The C library only reparents the planes bound to a root plane to the
+/// root plane itself, which would leave them pointing to freed memory,
+/// so they are turned into the roots of their own piles first, along with
+/// the planes bound to them.
+///
+/// # Safety
+///
+/// The pointer must be a valid plane that's not the standard plane.
+unsafe fn destroy_raw(raw: *mut NcPlane) -> i32 {
+    if core::ptr::eq(crate::ncplane_parent_const(raw), raw) {
+        let mut children = Vec::new();
+        let mut plane = crate::ncpile_top(raw);
+        while !plane.is_null() {
+            if plane != raw && core::ptr::eq(crate::ncplane_parent_const(plane), raw) {
+                children.push(plane);
+            }
+            plane = crate::ncplane_below(plane);
+        }
+        for child in children {
+            crate::ncplane_reparent_family(child, child);
+        }
+    }
+    NcPlaneExtra::free(raw);
+    crate::ncplane_destroy(raw)
+}
+
+/// An opaque identifier of an [`NcPlane`].
+///
+/// It's returned when looking up planes that can be owned by other handles.
+/// It can be compared with [`NcPlane::id`], but it gives no access to the
+/// plane, which may have been destroyed since.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NcPlaneId(pub(crate) NonNull<NcPlane>);
+
+impl NcPlaneId {
+    /// Identifies the plane at `raw`.
+    ///
+    /// Returns `None` if the pointer is null.
+    pub(crate) fn from_ptr(raw: *const NcPlane) -> Option<Self> {
+        NonNull::new(raw as *mut NcPlane).map(Self)
+    }
+}
+
+impl<'nc> Deref for NcPlaneOwned<'nc> {
+    type Target = NcPlane;
+
+    fn deref(&self) -> &NcPlane {
+        unsafe { self.raw.as_ref() }
+    }
+}
+
+impl<'nc> DerefMut for NcPlaneOwned<'nc> {
+    fn deref_mut(&mut self) -> &mut NcPlane {
+        unsafe { self.raw.as_mut() }
+    }
+}
@@ -0,0 +1,88 @@
+//! `NcSignalSet` methods and associated functions.
+
+use core::fmt;
+
+use crate::{
+    bindings::sigset_t, error::nc_result, sigaddset, sigdelset, sigemptyset, sigfillset,
+    sigismember, NcResult, NcSignalSet,
+};
+
+/// # `NcSignalSet` Constructors
+impl NcSignalSet {
+    /// New empty NcSignalSet.
+    pub fn new() -> Self {
+        let mut set = Self::zeroed();
+        unsafe { sigemptyset(&mut set.raw) };
+        set
+    }
+
+    /// New NcSignalSet with all the signals.
+    pub fn full() -> Self {
+        let mut set = Self::zeroed();
+        unsafe { sigfillset(&mut set.raw) };
+        set
+    }
+
+    /// New NcSignalSet with the provided signals.
+    pub fn with_signals(signals: &[i32]) -> NcResult<Self> {
+        let mut set = Self::new();
+        for signum in signals {
+            set.add(*signum)?;
+        }
+        Ok(set)
+    }
+
+    // private methods --
+
+    fn zeroed() -> Self {
+        Self {
+            raw: sigset_t { __val: [0; 16] },
+        }
+    }
+}
+
+/// # `NcSignalSet` Methods
+impl NcSignalSet {
+    /// Adds the signal to the set.
+    ///
+    /// Returns an error if it's not a valid signal number.
+    pub fn add(&mut self, signum: i32) -> NcResult<()> {
+        nc_result("sigaddset", unsafe { sigaddset(&mut self.raw, signum) })?;
+        Ok(())
+    }
+
+    /// Removes the signal from the set.
+    ///
+    /// Returns an error if it's not a valid signal number.
+    pub fn remove(&mut self, signum: i32) -> NcResult<()> {
+        nc_result("sigdelset", unsafe { sigdelset(&mut self.raw, signum) })?;
+        Ok(())
+    }
+
+    /// Is the signal in the set?
+    ///
+    /// Returns false if it's not a valid signal number.
+    pub fn contains(&self, signum: i32) -> bool {
+        unsafe { sigismember(&self.raw, signum) == 1 }
+    }
+
+    /// Returns a mutable pointer to the underlying `sigset_t`.
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut sigset_t {
+        &mut self.raw
+    }
+}
+
+impl Default for NcSignalSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for NcSignalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // signal numbers go from 1 to 64 on linux
+        f.debug_set()
+            .entries((1..=64).filter(|signum| self.contains(*signum)))
+            .finish()
+    }
+}
@@ -0,0 +1,32 @@
+//! `NcSignalSet`
+
+// functions already exported by bindgen : 5
+// ------------------------------------------
+// (W) wrap: 5 / 0
+// (#) test: 0 / 5
+// ------------------------------------------
+//W sigaddset
+//W sigdelset
+//W sigemptyset
+//W sigfillset
+//W sigismember
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+/// A set of signals, used as the signal mask while waiting for input.
+///
+/// The signals in the set are blocked while waiting, and the rest can
+/// interrupt the wait, e.g. an empty set lets `SIGCHLD` or `SIGUSR1`
+/// be delivered during a blocking read.
+///
+/// The signal numbers are the same as in the `libc` crate (e.g. `libc::SIGCHLD`).
+///
+/// See [`Notcurses.getc`][crate::Notcurses#method.getc].
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct NcSignalSet {
+    pub(crate) raw: crate::bindings::sigset_t,
+}
@@ -0,0 +1,36 @@
+//! Test `NcSignalSet` methods and associated functions.
+
+use crate::NcSignalSet;
+
+#[test]
+fn ncsignalset_add_remove() {
+    let mut set = NcSignalSet::new();
+    assert![!set.contains(libc::SIGCHLD)];
+
+    set.add(libc::SIGCHLD).unwrap();
+    set.add(libc::SIGUSR1).unwrap();
+    assert![set.contains(libc::SIGCHLD)];
+    assert![set.contains(libc::SIGUSR1)];
+    assert![!set.contains(libc::SIGINT)];
+
+    set.remove(libc::SIGCHLD).unwrap();
+    assert![!set.contains(libc::SIGCHLD)];
+    assert![set.contains(libc::SIGUSR1)];
+
+    assert![set.add(-1).is_err()];
+    assert![!set.contains(-1)];
+}
+
+#[test]
+fn ncsignalset_full() {
+    let set = NcSignalSet::full();
+    assert![set.contains(libc::SIGCHLD)];
+    assert![set.contains(libc::SIGWINCH)];
+
+    let set = NcSignalSet::with_signals(&[libc::SIGINT, libc::SIGTERM]).unwrap();
+    assert![set.contains(libc::SIGTERM)];
+    assert_eq![
+        format!["{{{}, {}}}", libc::SIGINT, libc::SIGTERM],
+        format!["{:?}", set]
+    ];
+}
@@ -0,0 +1,4 @@
+//! `NcSignalSet` tests.
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,256 @@
+//! `NcStats*` methods and associated functions.
+
+use core::{fmt, ops::Deref, time::Duration};
+use std::time::Instant;
+
+use crate::{error::nc_ptr, NcMetric, NcResult, NcStats, NcStatsDelta, NcStatsOwned, Notcurses};
+
+/// # `NcStats` Methods.
+impl NcStats {
+    /// Acquires an atomic snapshot of the Notcurses object's stats.
+    pub fn stats(&mut self, nc: &Notcurses) {
+        let _lock = crate::pile::raster_lock();
+        unsafe { crate::notcurses_stats(nc, self) }
+    }
+
+    /// Resets all cumulative stats (immediate ones are not reset).
+    pub fn reset(&mut self, nc: &mut Notcurses) {
+        let _lock = crate::pile::raster_lock();
+        unsafe { crate::notcurses_stats_reset(nc, self) }
+    }
+}
+
+/// # `NcStatsOwned` Constructors
+impl NcStatsOwned {
+    /// Allocates a new `NcStats` object and acquires a snapshot of the stats.
+    pub fn new(nc: &Notcurses) -> NcResult<Self> {
+        let raw = nc_ptr("notcurses_stats_alloc", unsafe {
+            crate::notcurses_stats_alloc(nc)
+        })?;
+        let mut stats = Self {
+            raw,
+            taken: Instant::now(),
+        };
+        stats.refresh(nc);
+        Ok(stats)
+    }
+}
+
+/// # `NcStatsOwned` Methods
+impl NcStatsOwned {
+    /// Acquires a new snapshot of the stats, overwriting this one.
+    pub fn refresh(&mut self, nc: &Notcurses) {
+        let _lock = crate::pile::raster_lock();
+        unsafe { crate::notcurses_stats(nc, self.raw.as_ptr()) }
+        self.taken = Instant::now();
+    }
+
+    /// Acquires a new snapshot of the stats, overwriting this one, and then
+    /// resets the cumulative stats of the context.
+    pub fn refresh_and_reset(&mut self, nc: &mut Notcurses) {
+        let _lock = crate::pile::raster_lock();
+        unsafe { crate::notcurses_stats_reset(nc, self.raw.as_ptr()) }
+        self.taken = Instant::now();
+    }
+
+    /// Returns the instant the snapshot was taken.
+    pub fn taken(&self) -> Instant {
+        self.taken
+    }
+
+    /// Returns the stats accrued since the `earlier` snapshot.
+    ///
+    /// This is the same as `self - earlier`.
+    pub fn since(&self, earlier: &NcStatsOwned) -> NcStatsDelta {
+        let interval = self.taken.saturating_duration_since(earlier.taken);
+        NcStatsDelta::between(earlier, interval, self)
+    }
+}
+
+impl Drop for NcStatsOwned {
+    fn drop(&mut self) {
+        unsafe { libc::free(self.raw.as_ptr() as *mut _) }
+    }
+}
+
+impl Deref for NcStatsOwned {
+    type Target = NcStats;
+    fn deref(&self) -> &NcStats {
+        unsafe { self.raw.as_ref() }
+    }
+}
+
+impl<'a> core::ops::Sub<&'a NcStatsOwned> for &'a NcStatsOwned {
+    type Output = NcStatsDelta;
+    fn sub(self, earlier: &'a NcStatsOwned) -> NcStatsDelta {
+        self.since(earlier)
+    }
+}
+
+/// A report of the stats, one per line, using metric prefixes.
+impl fmt::Display for NcStatsOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s: &NcStats = self;
+        writeln!(
+            f,
+            "renders:   {} ({} failed, {} refreshes)",
+            s.renders, s.failed_renders, s.refreshes
+        )?;
+        writeln!(
+            f,
+            "bytes:     {}B (min {}B, max {}B per frame)",
+            bytes(s.render_bytes),
+            bytes(s.render_min_bytes.max(0) as u64),
+            bytes(s.render_max_bytes.max(0) as u64),
+        )?;
+        writeln!(
+            f,
+            "render:    {}s (min {}s, max {}s per frame)",
+            seconds(s.render_ns),
+            seconds(s.render_min_ns.max(0) as u64),
+            seconds(s.render_max_ns.max(0) as u64),
+        )?;
+        writeln!(
+            f,
+            "writeout:  {}s (min {}s, max {}s per frame)",
+            seconds(s.writeout_ns),
+            seconds(s.writeout_min_ns.max(0) as u64),
+            seconds(s.writeout_max_ns.max(0) as u64),
+        )?;
+        writeln!(
+            f,
+            "cells:     {} emitted, {} elided",
+            units(s.cellemissions),
+            units(s.cellelisions)
+        )?;
+        write!(
+            f,
+            "planes:    {} ({}B of framebuffers)",
+            s.planes,
+            bytes(s.fbbytes)
+        )
+    }
+}
+
+/// # `Notcurses` stats
+impl Notcurses {
+    /// Returns an owned snapshot of the stats.
+    pub fn stats(&self) -> NcResult<NcStatsOwned> {
+        NcStatsOwned::new(self)
+    }
+}
+
+/// # `NcStatsDelta` Methods
+impl NcStatsDelta {
+    /// Returns the stats accrued from `earlier` to `later`,
+    /// during an `interval`.
+    pub fn between(earlier: &NcStats, interval: Duration, later: &NcStats) -> Self {
+        Self {
+            interval,
+            renders: later.renders.saturating_sub(earlier.renders),
+            failed_renders: later.failed_renders.saturating_sub(earlier.failed_renders),
+            render_bytes: later.render_bytes.saturating_sub(earlier.render_bytes),
+            render_ns: later.render_ns.saturating_sub(earlier.render_ns),
+            writeout_ns: later.writeout_ns.saturating_sub(earlier.writeout_ns),
+            cellelisions: later.cellelisions.saturating_sub(earlier.cellelisions),
+            cellemissions: later.cellemissions.saturating_sub(earlier.cellemissions),
+            refreshes: later.refreshes.saturating_sub(earlier.refreshes),
+        }
+    }
+
+    /// Returns the number of renders per second.
+    pub fn renders_per_sec(&self) -> f64 {
+        self.per_sec(self.renders)
+    }
+
+    /// Returns the number of bytes emitted per second.
+    pub fn bytes_per_sec(&self) -> f64 {
+        self.per_sec(self.render_bytes)
+    }
+
+    /// Returns the average bytes emitted per render, if there were any renders.
+    pub fn avg_bytes(&self) -> Option<u64> {
+        self.per_render(self.render_bytes)
+    }
+
+    /// Returns the average nanoseconds spent in render+raster per render,
+    /// if there were any renders.
+    pub fn avg_render_ns(&self) -> Option<u64> {
+        self.per_render(self.render_ns)
+    }
+
+    /// Returns the average nanoseconds spent writing out per render,
+    /// if there were any renders.
+    pub fn avg_writeout_ns(&self) -> Option<u64> {
+        self.per_render(self.writeout_ns)
+    }
+
+    fn per_sec(&self, count: u64) -> f64 {
+        let secs = self.interval.as_secs_f64();
+        if secs > 0.0 {
+            count as f64 / secs
+        } else {
+            0.0
+        }
+    }
+
+    fn per_render(&self, count: u64) -> Option<u64> {
+        count.checked_div(self.renders)
+    }
+}
+
+/// A report of the rates, one per line, using metric prefixes.
+impl fmt::Display for NcStatsDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let avg_seconds = |ns: Option<u64>| ns.map_or("-".into(), |ns| seconds(ns) + "s");
+        writeln!(
+            f,
+            "interval:  {}s",
+            seconds(self.interval.as_nanos() as u64)
+        )?;
+        writeln!(
+            f,
+            "renders:   {}/s ({} failed)",
+            rate(self.renders_per_sec()),
+            self.failed_renders
+        )?;
+        writeln!(
+            f,
+            "bytes:     {}B/s ({}B per frame)",
+            bytes(self.bytes_per_sec() as u64),
+            bytes(self.avg_bytes().unwrap_or(0))
+        )?;
+        writeln!(
+            f,
+            "render:    {} per frame",
+            avg_seconds(self.avg_render_ns())
+        )?;
+        write!(
+            f,
+            "writeout:  {} per frame",
+            avg_seconds(self.avg_writeout_ns())
+        )
+    }
+}
+
+// A count, with SI prefixes.
+fn units(val: u64) -> String {
+    NcMetric::si(val).omit_decimals().to_string()
+}
+
+// A rate, with SI prefixes and two decimals.
+fn rate(val: f64) -> String {
+    let val = (val * 100.0).round() as u64;
+    NcMetric::new(val, 100, 1000, None).unwrap().to_string()
+}
+
+// A number of bytes, with IEC prefixes.
+fn bytes(val: u64) -> String {
+    NcMetric::iec(val).omit_decimals().to_string()
+}
+
+// A number of nanoseconds, as seconds with SI prefixes.
+fn seconds(ns: u64) -> String {
+    let metric = NcMetric::new(ns, 1_000_000_000, 1000, None).unwrap();
+    metric.omit_decimals().to_string()
+}
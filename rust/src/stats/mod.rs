@@ -0,0 +1,61 @@
+//! `NcStats`
+
+// functions already exported by bindgen : 3
+// ------------------------------------------
+// (W) wrap: 3 / 0
+// (#) test: 0 / 3
+// ------------------------------------------
+//W notcurses_stats
+//W notcurses_stats_alloc
+//W notcurses_stats_reset
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+use core::{ptr::NonNull, time::Duration};
+use std::time::Instant;
+
+/// notcurses runtime statistics
+pub type NcStats = crate::bindings::ffi::ncstats;
+
+/// An owned snapshot of the [`NcStats`] of a [`Notcurses`][crate::Notcurses]
+/// context, that frees its allocation when dropped.
+///
+/// It dereferences to the raw `NcStats`, and remembers when it was taken,
+/// so that subtracting an earlier snapshot from a later one returns
+/// the [`NcStatsDelta`] of that interval.
+#[derive(Debug)]
+pub struct NcStatsOwned {
+    raw: NonNull<NcStats>,
+    taken: Instant,
+}
+
+/// The difference between two [`NcStatsOwned`] snapshots.
+///
+/// Contains the cumulative stats accrued during the interval between them,
+/// from which the rates can be calculated.
+///
+/// The counters saturate at zero if the stats were reset in between.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NcStatsDelta {
+    /// The time elapsed between the two snapshots.
+    pub interval: Duration,
+    /// The number of successful renders.
+    pub renders: u64,
+    /// The number of aborted renders.
+    pub failed_renders: u64,
+    /// The bytes emitted to the terminal.
+    pub render_bytes: u64,
+    /// The nanoseconds spent in render+raster.
+    pub render_ns: u64,
+    /// The nanoseconds spent writing frames to the terminal.
+    pub writeout_ns: u64,
+    /// The cells elided entirely thanks to damage maps.
+    pub cellelisions: u64,
+    /// The cells emitted to the terminal.
+    pub cellemissions: u64,
+    /// The refresh requests (non-optimized redraws).
+    pub refreshes: u64,
+}
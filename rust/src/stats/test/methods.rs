@@ -0,0 +1,52 @@
+//! Test `NcStats*` methods and associated functions.
+
+use core::time::Duration;
+
+use serial_test::serial;
+
+use crate::{FullMode, NcStats, NcStatsDelta};
+
+fn zeroed() -> NcStats {
+    unsafe { core::mem::zeroed() }
+}
+
+#[test]
+fn ncstatsdelta_rates() {
+    let earlier = zeroed();
+    let mut later = zeroed();
+    later.renders = 120;
+    later.render_bytes = 12_000;
+    later.render_ns = 60_000_000;
+    later.writeout_ns = 12_000_000;
+
+    let delta = NcStatsDelta::between(&earlier, Duration::from_secs(2), &later);
+    assert_eq![120, delta.renders];
+    assert_eq![60.0, delta.renders_per_sec()];
+    assert_eq![6_000.0, delta.bytes_per_sec()];
+    assert_eq![Some(100), delta.avg_bytes()];
+    assert_eq![Some(500_000), delta.avg_render_ns()];
+    assert_eq![Some(100_000), delta.avg_writeout_ns()];
+
+    let report = delta.to_string();
+    assert![report.starts_with("interval:  2s\nrenders:   60.00/s (0 failed)\n")];
+    assert![report.contains("bytes:     5.86KiB/s (100B per frame)\n")];
+    assert![report.ends_with("writeout:  100µs per frame")];
+
+    // the counters saturate when the stats were reset in between
+    let reset = NcStatsDelta::between(&later, Duration::from_secs(1), &earlier);
+    assert_eq![0, reset.renders];
+    assert_eq![None, reset.avg_render_ns()];
+    assert_eq![0.0, NcStatsDelta::default().renders_per_sec()];
+}
+
+#[test]
+#[serial]
+fn ncstatsowned_delta() {
+    let nc = FullMode::new().unwrap();
+    let earlier = nc.stats().unwrap();
+    nc.render().unwrap();
+    let later = nc.stats().unwrap();
+    let delta = &later - &earlier;
+    assert_eq![1, delta.renders];
+    print!("[{}] ", later);
+}
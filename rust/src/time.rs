@@ -1,6 +1,26 @@
 //! `NcTime`
 
+use core::time::Duration;
+
 ///
-// only used for now with [`notcurses_getc_nblock`], which can't use
+// only used for now with `notcurses_getc`, which can't use
 // libc::timespec
 pub type NcTime = crate::bindings::ffi::timespec;
+
+impl NcTime {
+    /// New NcTime, expects seconds and nanoseconds.
+    pub fn new(seconds: i64, nanoseconds: i64) -> Self {
+        Self {
+            tv_sec: seconds as _,
+            tv_nsec: nanoseconds as _,
+        }
+    }
+}
+
+/// The seconds of durations too long for an `i64` saturate to `i64::MAX`.
+impl From<Duration> for NcTime {
+    fn from(duration: Duration) -> Self {
+        let seconds = duration.as_secs().min(i64::MAX as u64) as i64;
+        Self::new(seconds, duration.subsec_nanos() as i64)
+    }
+}
@@ -0,0 +1,126 @@
+//! `NcVersion` methods and associated functions.
+
+use core::{fmt, str::FromStr};
+use std::ffi::CStr;
+
+use crate::{NcParseError, NcVersion, NcVersionMismatch};
+
+/// # `NcVersion` Constructors
+impl NcVersion {
+    /// New `NcVersion`.
+    pub const fn new(major: i32, minor: i32, patch: i32, tweak: i32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            tweak,
+        }
+    }
+
+    /// Returns the version of the Notcurses library linked at runtime.
+    pub fn runtime() -> Self {
+        let mut v = Self::default();
+        unsafe {
+            crate::notcurses_version_components(
+                &mut v.major,
+                &mut v.minor,
+                &mut v.patch,
+                &mut v.tweak,
+            );
+        }
+        v
+    }
+
+    /// Returns the version of the Notcurses headers the bindings were
+    /// generated from, at build time.
+    pub fn compiled() -> Self {
+        COMPILED_VERSION
+            .parse()
+            .expect("the build script exposed an invalid version")
+    }
+}
+
+/// # `NcVersion` Methods
+impl NcVersion {
+    /// Returns the human-readable version string of the library linked at
+    /// runtime, as returned by [`notcurses_version`][crate::notcurses_version].
+    pub fn runtime_string() -> String {
+        unsafe {
+            CStr::from_ptr(crate::notcurses_version())
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /// Checks that the version of the library linked at runtime is the same
+    /// as the version of the headers seen at build time, ignoring the `tweak`.
+    ///
+    /// Returns the runtime version on success.
+    pub fn check() -> Result<NcVersion, NcVersionMismatch> {
+        Self::check_versions(Self::runtime(), Self::compiled())
+    }
+
+    /// Checks that the `runtime` and `compiled` versions are the same,
+    /// ignoring the `tweak`.
+    ///
+    /// Returns the runtime version on success.
+    pub fn check_versions(
+        runtime: NcVersion,
+        compiled: NcVersion,
+    ) -> Result<NcVersion, NcVersionMismatch> {
+        if (runtime.major, runtime.minor, runtime.patch)
+            == (compiled.major, compiled.minor, compiled.patch)
+        {
+            Ok(runtime)
+        } else {
+            Err(NcVersionMismatch { runtime, compiled })
+        }
+    }
+}
+
+// The version reported by pkg-config for the headers used by bindgen.
+const COMPILED_VERSION: &str = env!("NOTCURSES_BUILD_VERSION");
+
+/// Prints `major.minor.patch`, followed by `.tweak` if it's not 0.
+impl fmt::Display for NcVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if self.tweak != 0 {
+            write!(f, ".{}", self.tweak)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses from one to four non-negative integers separated by dots,
+/// e.g. `2.1` or `2.1.0.3`. The missing components are 0.
+impl FromStr for NcVersion {
+    type Err = NcParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || NcParseError::new("NcVersion", s);
+        let mut components = [0; 4];
+        for (i, part) in s.trim().split('.').enumerate() {
+            let component = components.get_mut(i).ok_or_else(err)?;
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(err());
+            }
+            *component = part.parse().map_err(|_| err())?;
+        }
+        let [major, minor, patch, tweak] = components;
+        Ok(Self::new(major, minor, patch, tweak))
+    }
+}
+
+impl fmt::Display for NcVersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the linked notcurses library is version {}, \
+            but the bindings were generated for version {}",
+            self.runtime, self.compiled
+        )
+    }
+}
+
+impl std::error::Error for NcVersionMismatch {}
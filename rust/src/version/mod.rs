@@ -0,0 +1,43 @@
+//! `NcVersion`
+
+// functions already exported by bindgen : 2
+// ------------------------------------------
+// (W) wrap: 2 / 0
+// (#) test: 0 / 2
+// ------------------------------------------
+//W notcurses_version
+//W notcurses_version_components
+
+#[cfg(test)]
+mod test;
+
+mod methods;
+
+/// The version of the Notcurses library, or of its headers.
+///
+/// Compares in version order, first by `major`, and lastly by `tweak`.
+///
+/// See [`NcVersion::runtime`], [`NcVersion::compiled`]
+/// and [`NcVersion::check`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NcVersion {
+    /// The major version number.
+    pub major: i32,
+    /// The minor version number.
+    pub minor: i32,
+    /// The patch version number.
+    pub patch: i32,
+    /// The tweak version number.
+    pub tweak: i32,
+}
+
+/// The error returned by [`NcVersion::check`] when the version of the linked
+/// library differs from the version of the headers the bindings were
+/// generated from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NcVersionMismatch {
+    /// The version of the library linked at runtime.
+    pub runtime: NcVersion,
+    /// The version of the headers seen at build time.
+    pub compiled: NcVersion,
+}
@@ -0,0 +1,59 @@
+//! Test `NcVersion` methods and associated functions.
+
+use crate::{NcVersion, NcVersionMismatch};
+
+#[test]
+fn ncversion_parse_and_display() {
+    assert_eq![Ok(NcVersion::new(2, 1, 0, 0)), "2.1.0".parse()];
+    assert_eq![Ok(NcVersion::new(2, 1, 0, 3)), "2.1.0.3".parse()];
+    assert_eq![Ok(NcVersion::new(2, 0, 0, 0)), "2".parse()];
+    assert![" ".parse::<NcVersion>().is_err()];
+    assert!["2..1".parse::<NcVersion>().is_err()];
+    assert!["2.1.0.0.1".parse::<NcVersion>().is_err()];
+    assert!["2.-1.0".parse::<NcVersion>().is_err()];
+    assert!["2.1.0-rc1".parse::<NcVersion>().is_err()];
+
+    assert_eq!["2.1.0", NcVersion::new(2, 1, 0, 0).to_string()];
+    assert_eq!["2.1.0.3", NcVersion::new(2, 1, 0, 3).to_string()];
+    assert![NcVersion::new(2, 1, 0, 0) < NcVersion::new(2, 1, 1, 0)];
+    assert![NcVersion::new(2, 0, 9, 9) < NcVersion::new(2, 1, 0, 0)];
+}
+
+#[test]
+fn ncversion_compiled() {
+    let compiled = NcVersion::compiled();
+    assert![compiled >= NcVersion::new(2, 1, 0, 0)];
+}
+
+#[test]
+fn ncversion_check_versions() {
+    let (v210, v211) = (NcVersion::new(2, 1, 0, 0), NcVersion::new(2, 1, 1, 0));
+    assert_eq![Ok(v210), NcVersion::check_versions(v210, v210)];
+    assert_eq![
+        Ok(NcVersion::new(2, 1, 0, 7)),
+        NcVersion::check_versions(NcVersion::new(2, 1, 0, 7), v210)
+    ];
+
+    let mismatch = NcVersion::check_versions(v211, v210).unwrap_err();
+    assert_eq![
+        NcVersionMismatch {
+            runtime: v211,
+            compiled: v210
+        },
+        mismatch
+    ];
+    assert_eq![
+        "the linked notcurses library is version 2.1.1, \
+        but the bindings were generated for version 2.1.0",
+        mismatch.to_string()
+    ];
+}
+
+#[test]
+fn ncversion_runtime() {
+    let runtime = NcVersion::runtime();
+    assert![runtime
+        .to_string()
+        .starts_with(&NcVersion::runtime_string())];
+    print!("[{}] ", runtime);
+}
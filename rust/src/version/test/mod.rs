@@ -0,0 +1,4 @@
+//! `NcVersion` tests
+
+#[cfg(test)]
+mod methods;
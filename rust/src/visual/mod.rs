@@ -0,0 +1,154 @@
+//! `ncvisual`
+
+// TODO: implement constructors
+
+// functions already exported by bindgen : 17
+// -----------------------------------------
+// (#) test: 0 / 17
+// -----------------------------------------
+//  ncvisual_at_yx
+//  ncvisual_decode
+//  ncvisual_decode_loop
+//  ncvisual_destroy
+//  ncvisual_from_bgra
+//  ncvisual_from_file
+//  ncvisual_from_plane
+//  ncvisual_from_rgba
+//  ncvisual_geom
+//  ncvisual_polyfill_yx
+//  ncvisual_render
+//  ncvisual_resize
+//  ncvisual_rotate
+//  ncvisual_set_yx
+//  ncvisual_simple_streamer
+//  ncvisual_stream
+//  ncvisual_subtitle
+//
+// functions manually reimplemented: 1
+// ------------------------------------------
+// (+) done: 1 / 0
+// (#) test: 0 / 1
+// ------------------------------------------
+// + ncvisual_default_blitter
+
+#[cfg(test)]
+mod test;
+
+use core::{fmt, str::FromStr};
+use std::ffi::CString;
+
+use crate::{NCBLIT_1x1, NCBLIT_2x1, NCBLIT_2x2, NcBlitter, NcParseError};
+
+/// How to scale an [`NcVisual`] during rendering
+///
+/// - NCSCALE_NONE will apply no scaling.
+/// - NCSCALE_SCALE scales a visual to the plane's size,
+///   maintaining aspect ratio.
+/// - NCSCALE_STRETCH stretches and scales the image in an
+///   attempt to fill the entirety of the plane.
+///
+/// See also [`NcScaleKind`], used by the safe API.
+pub type NcScale = crate::bindings::ffi::ncscale_e;
+/// Maintain original size
+pub const NCSCALE_NONE: NcScale = crate::bindings::ffi::ncscale_e_NCSCALE_NONE;
+/// Maintain aspect ratio
+pub const NCSCALE_SCALE: NcScale = crate::bindings::ffi::ncscale_e_NCSCALE_SCALE;
+/// Throw away aspect ratio
+pub const NCSCALE_STRETCH: NcScale = crate::bindings::ffi::ncscale_e_NCSCALE_STRETCH;
+
+/// How to scale an [`NcVisual`] during rendering, used by the safe API.
+///
+/// It can be parsed from, and is displayed as, the names used by the
+/// [`notcurses_lex_scalemode`][crate::notcurses_lex_scalemode] &
+/// [`notcurses_str_scalemode`][crate::notcurses_str_scalemode] functions:
+/// `"none"`, `"scale"` & `"stretch"`.
+///
+/// See also [`NcScale`], used by the C API.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NcScaleKind {
+    /// Maintain original size ([`NCSCALE_NONE`]).
+    None = NCSCALE_NONE,
+
+    /// Maintain aspect ratio ([`NCSCALE_SCALE`]).
+    Scale = NCSCALE_SCALE,
+
+    /// Throw away aspect ratio ([`NCSCALE_STRETCH`]).
+    Stretch = NCSCALE_STRETCH,
+}
+
+impl NcScaleKind {
+    /// All the scaling modes.
+    pub const ALL: [NcScaleKind; 3] = [NcScaleKind::None, NcScaleKind::Scale, NcScaleKind::Stretch];
+
+    /// Returns the scaling mode corresponding to an [`NcScale`], if any.
+    pub fn from_u32(scale: NcScale) -> Option<Self> {
+        Self::ALL.iter().copied().find(|s| *s as NcScale == scale)
+    }
+
+    /// Returns the name of the scaling mode, as returned by
+    /// [`notcurses_str_scalemode`][crate::notcurses_str_scalemode].
+    pub fn name(self) -> &'static str {
+        crate::error::nc_static_str(unsafe { crate::notcurses_str_scalemode(self.into()) })
+    }
+}
+
+impl Default for NcScaleKind {
+    fn default() -> Self {
+        NcScaleKind::None
+    }
+}
+
+impl From<NcScaleKind> for NcScale {
+    fn from(scale: NcScaleKind) -> Self {
+        scale as NcScale
+    }
+}
+
+impl fmt::Display for NcScaleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the name of a scaling mode, case insensitively,
+/// using [`notcurses_lex_scalemode`][crate::notcurses_lex_scalemode].
+impl FromStr for NcScaleKind {
+    type Err = NcParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || NcParseError::new("NcScaleKind", s);
+        let cs = CString::new(s).map_err(|_| err())?;
+        let mut scale: NcScale = 0;
+        if unsafe { crate::notcurses_lex_scalemode(cs.as_ptr(), &mut scale) } < 0 {
+            return Err(err());
+        }
+        Self::from_u32(scale).ok_or_else(err)
+    }
+}
+
+/// A visual bit of multimedia opened with LibAV|OIIO
+pub type NcVisual = crate::bindings::ffi::ncvisual;
+/// Options struct for [`NcVisual`]
+pub type NcVisualOptions = crate::bindings::ffi::ncvisual_options;
+
+/// Uses [`NCCELL_ALPHA_BLEND`][crate::NCCELL_ALPHA_BLEND] with visual.
+pub const NCVISUAL_OPTION_BLEND: u32 = crate::bindings::ffi::NCVISUAL_OPTION_BLEND;
+
+/// Fails rather than degrade.
+pub const NCVISUAL_OPTION_NODEGRADE: u32 = crate::bindings::ffi::NCVISUAL_OPTION_NODEGRADE;
+
+/// Returns the best default blitter available
+///
+/// NCBLIT_3x2 is better image quality, especially for large images, but
+/// it's not the general default because it doesn't preserve aspect ratio.
+/// NCSCALE_STRETCH throws away aspect ratio, and can safely use NCBLIT_3x2.
+pub fn ncvisual_default_blitter(utf8: bool, scale: NcScale) -> NcBlitter {
+    if utf8 {
+        if scale == NCSCALE_STRETCH {
+            return NCBLIT_2x2;
+        }
+        return NCBLIT_2x1;
+    }
+    NCBLIT_1x1
+}
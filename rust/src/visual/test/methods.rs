@@ -0,0 +1,16 @@
+//! Test `NcScaleKind` methods and trait implementations.
+
+use crate::{NcScaleKind, NCSCALE_STRETCH};
+
+#[test]
+fn ncscale_lex() {
+    for scale in NcScaleKind::ALL.iter() {
+        assert_eq![Ok(*scale), scale.to_string().parse()];
+    }
+    assert_eq!["stretch", NcScaleKind::Stretch.to_string()];
+    assert_eq![
+        Some(NcScaleKind::Stretch),
+        NcScaleKind::from_u32(NCSCALE_STRETCH)
+    ];
+    assert!["fit".parse::<NcScaleKind>().is_err()];
+}
@@ -1,20 +1,26 @@
 //! `NcMenu*` methods and associated functions.
 
+use std::ffi::CStr;
+
 use crate::{
-    cstring, ncmenu_create, NcChannelPair, NcInput, NcMenu, NcMenuItem, NcMenuOptions,
-    NcMenuSection, NcPlane,
+    cstring, error::nc_ptr, ncmenu_create, NcChannelPair, NcChord, NcInput, NcMenu, NcMenuItem,
+    NcMenuOptions, NcMenuSection, NcParseError, NcPlane, NcResult,
 };
 
 /// # `NcMenu` Constructors
 impl NcMenu {
     /// `NcMenu` simple constructor
-    pub unsafe fn new<'a>(plane: &mut NcPlane) -> &'a mut Self {
+    pub unsafe fn new<'a>(plane: &mut NcPlane) -> NcResult<&'a mut Self> {
         Self::with_options(plane, &NcMenuOptions::new())
     }
 
     /// `NcMenu` constructor with options
-    pub unsafe fn with_options<'a>(plane: &mut NcPlane, options: &NcMenuOptions) -> &'a mut Self {
-        &mut *ncmenu_create(plane, options)
+    pub unsafe fn with_options<'a>(
+        plane: &mut NcPlane,
+        options: &NcMenuOptions,
+    ) -> NcResult<&'a mut Self> {
+        let widget = nc_ptr("ncmenu_create", ncmenu_create(plane, options))?;
+        Ok(&mut *widget.as_ptr())
     }
 }
 
@@ -64,6 +70,19 @@ impl NcMenuItem {
             shortcut,
         }
     }
+
+    /// `NcMenuItem` constructor with a shortcut parsed from an [`NcChord`]
+    /// string, e.g. `"C-q"`.
+    ///
+    /// # Safety
+    ///
+    /// The item points to the `desc` string, which must outlive it.
+    pub unsafe fn with_shortcut(desc: &CStr, shortcut: &str) -> Result<Self, NcParseError> {
+        Ok(Self {
+            desc: desc.as_ptr() as *mut _,
+            shortcut: shortcut.parse::<NcChord>()?.into(),
+        })
+    }
 }
 
 /// # `NcMenuSection` Constructors
@@ -84,4 +103,24 @@ impl NcMenuSection {
             shortcut,
         }
     }
+
+    /// `NcMenuSection` constructor with a shortcut parsed from an [`NcChord`]
+    /// string, e.g. `"M-f"`.
+    ///
+    /// # Safety
+    ///
+    /// The section points to the `name` string and the `items`, which must
+    /// outlive it.
+    pub unsafe fn with_shortcut(
+        name: &CStr,
+        items: &mut [NcMenuItem],
+        shortcut: &str,
+    ) -> Result<Self, NcParseError> {
+        Ok(Self {
+            name: name.as_ptr() as *mut _,
+            itemcount: items.len() as i32,
+            items: items.as_mut_ptr(),
+            shortcut: shortcut.parse::<NcChord>()?.into(),
+        })
+    }
 }
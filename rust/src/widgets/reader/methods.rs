@@ -1,6 +1,6 @@
 //! `NcReader*` methods and associated functions.
 
-use crate::{ncreader_create, NcPlane, NcReader, NcReaderOptions};
+use crate::{error::nc_ptr, ncreader_create, NcPlane, NcReader, NcReaderOptions, NcResult};
 
 /// # `NcReaderOptions` Constructors
 impl NcReaderOptions {
@@ -20,12 +20,16 @@ impl NcReaderOptions {
 /// # `NcReader` Constructors
 impl NcReader {
     /// `NcReader` simple constructor
-    pub unsafe fn new<'a>(plane: &mut NcPlane) -> &'a mut Self {
+    pub unsafe fn new<'a>(plane: &mut NcPlane) -> NcResult<&'a mut Self> {
         Self::with_options(plane, &NcReaderOptions::new())
     }
 
     /// `NcReader` constructor with options
-    pub unsafe fn with_options<'a>(plane: &mut NcPlane, options: &NcReaderOptions) -> &'a mut Self {
-        &mut *ncreader_create(plane, options)
+    pub unsafe fn with_options<'a>(
+        plane: &mut NcPlane,
+        options: &NcReaderOptions,
+    ) -> NcResult<&'a mut Self> {
+        let widget = nc_ptr("ncreader_create", ncreader_create(plane, options))?;
+        Ok(&mut *widget.as_ptr())
     }
 }